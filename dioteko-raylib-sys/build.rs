@@ -1,10 +1,20 @@
+use std::env;
 use std::fs;
 
 fn main() -> std::io::Result<()> {
     // Build raylib with cmake
-    let dst = cmake::Config::new("./lib/raylib")
-        .very_verbose(true)
-        .build();
+    let mut config = cmake::Config::new("./lib/raylib");
+    config.very_verbose(true);
+
+    // EndDrawing does not swap buffers and poll events anymore with this.
+    // Instead, SwapScreenBuffer and PollInputEvents should be called manually
+    if env::var_os("CARGO_FEATURE_CUSTOM_FRAME_CONTROL").is_some() {
+        config
+            .define("CUSTOMIZE_BUILD", "ON")
+            .define("SUPPORT_CUSTOM_FRAME_CONTROL", "ON");
+    }
+
+    let dst = config.build();
 
     // Use prebuilt bindings.rs
     #[cfg(target_os = "windows")]
@@ -2339,6 +2339,27 @@ fn bindgen_test_layout_VrStereoConfig() {
         )
     );
 }
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FilePathList {
+    pub capacity: ::std::os::raw::c_uint,
+    pub count: ::std::os::raw::c_uint,
+    pub paths: *mut *mut ::std::os::raw::c_char,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AutomationEvent {
+    pub frame: ::std::os::raw::c_uint,
+    pub type_: ::std::os::raw::c_uint,
+    pub params: [::std::os::raw::c_int; 4usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AutomationEventList {
+    pub capacity: ::std::os::raw::c_uint,
+    pub count: ::std::os::raw::c_uint,
+    pub events: *mut AutomationEvent,
+}
 pub const ConfigFlags_FLAG_VSYNC_HINT: ConfigFlags = 64;
 pub const ConfigFlags_FLAG_FULLSCREEN_MODE: ConfigFlags = 2;
 pub const ConfigFlags_FLAG_WINDOW_RESIZABLE: ConfigFlags = 4;
@@ -2351,6 +2372,8 @@ pub const ConfigFlags_FLAG_WINDOW_TOPMOST: ConfigFlags = 4096;
 pub const ConfigFlags_FLAG_WINDOW_ALWAYS_RUN: ConfigFlags = 256;
 pub const ConfigFlags_FLAG_WINDOW_TRANSPARENT: ConfigFlags = 16;
 pub const ConfigFlags_FLAG_WINDOW_HIGHDPI: ConfigFlags = 8192;
+pub const ConfigFlags_FLAG_WINDOW_MOUSE_PASSTHROUGH: ConfigFlags = 16384;
+pub const ConfigFlags_FLAG_BORDERLESS_WINDOWED_MODE: ConfigFlags = 32768;
 pub const ConfigFlags_FLAG_MSAA_4X_HINT: ConfigFlags = 32;
 pub const ConfigFlags_FLAG_INTERLACED_HINT: ConfigFlags = 65536;
 pub type ConfigFlags = ::std::os::raw::c_int;
@@ -2726,6 +2749,9 @@ extern "C" {
 extern "C" {
     pub fn ToggleFullscreen();
 }
+extern "C" {
+    pub fn ToggleBorderlessWindowed();
+}
 extern "C" {
     pub fn MaximizeWindow();
 }
@@ -2750,9 +2776,18 @@ extern "C" {
 extern "C" {
     pub fn SetWindowMinSize(width: ::std::os::raw::c_int, height: ::std::os::raw::c_int);
 }
+extern "C" {
+    pub fn SetWindowMaxSize(width: ::std::os::raw::c_int, height: ::std::os::raw::c_int);
+}
 extern "C" {
     pub fn SetWindowSize(width: ::std::os::raw::c_int, height: ::std::os::raw::c_int);
 }
+extern "C" {
+    pub fn SetWindowOpacity(opacity: f32);
+}
+extern "C" {
+    pub fn SetWindowFocused();
+}
 extern "C" {
     pub fn GetWindowHandle() -> *mut ::std::os::raw::c_void;
 }
@@ -2807,6 +2842,12 @@ extern "C" {
 extern "C" {
     pub fn GetClipboardText() -> *const ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn EnableEventWaiting();
+}
+extern "C" {
+    pub fn DisableEventWaiting();
+}
 extern "C" {
     pub fn SwapScreenBuffer();
 }
@@ -2814,7 +2855,7 @@ extern "C" {
     pub fn PollInputEvents();
 }
 extern "C" {
-    pub fn WaitTime(ms: f32);
+    pub fn WaitTime(seconds: f64);
 }
 extern "C" {
     pub fn ShowCursor();
@@ -3067,6 +3108,33 @@ extern "C" {
 extern "C" {
     pub fn FileExists(fileName: *const ::std::os::raw::c_char) -> bool_;
 }
+extern "C" {
+    pub fn LoadAutomationEventList(fileName: *const ::std::os::raw::c_char) -> AutomationEventList;
+}
+extern "C" {
+    pub fn UnloadAutomationEventList(list: *mut AutomationEventList);
+}
+extern "C" {
+    pub fn ExportAutomationEventList(
+        list: AutomationEventList,
+        fileName: *const ::std::os::raw::c_char,
+    ) -> bool_;
+}
+extern "C" {
+    pub fn SetAutomationEventList(list: *mut AutomationEventList);
+}
+extern "C" {
+    pub fn SetAutomationEventBaseFrame(frame: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn StartAutomationEventRecording();
+}
+extern "C" {
+    pub fn StopAutomationEventRecording();
+}
+extern "C" {
+    pub fn PlayAutomationEvent(event: AutomationEvent);
+}
 extern "C" {
     pub fn DirectoryExists(dirPath: *const ::std::os::raw::c_char) -> bool_;
 }
@@ -3123,6 +3191,12 @@ extern "C" {
 extern "C" {
     pub fn ClearDroppedFiles();
 }
+extern "C" {
+    pub fn LoadDroppedFiles() -> FilePathList;
+}
+extern "C" {
+    pub fn UnloadDroppedFiles(files: FilePathList);
+}
 extern "C" {
     pub fn GetFileModTime(fileName: *const ::std::os::raw::c_char) -> ::std::os::raw::c_long;
 }
@@ -3168,6 +3242,9 @@ extern "C" {
 extern "C" {
     pub fn IsKeyPressed(key: ::std::os::raw::c_int) -> bool_;
 }
+extern "C" {
+    pub fn IsKeyPressedRepeat(key: ::std::os::raw::c_int) -> bool_;
+}
 extern "C" {
     pub fn IsKeyDown(key: ::std::os::raw::c_int) -> bool_;
 }
@@ -3267,6 +3344,9 @@ extern "C" {
 extern "C" {
     pub fn GetMouseWheelMove() -> f32;
 }
+extern "C" {
+    pub fn GetMouseWheelMoveV() -> Vector2;
+}
 extern "C" {
     pub fn SetMouseCursor(cursor: ::std::os::raw::c_int);
 }
@@ -3638,6 +3718,9 @@ extern "C" {
 extern "C" {
     pub fn LoadImage(fileName: *const ::std::os::raw::c_char) -> Image;
 }
+extern "C" {
+    pub fn IsImageReady(image: Image) -> bool_;
+}
 extern "C" {
     pub fn LoadImageRaw(
         fileName: *const ::std::os::raw::c_char,
@@ -3982,6 +4065,12 @@ extern "C" {
 extern "C" {
     pub fn UnloadRenderTexture(target: RenderTexture2D);
 }
+extern "C" {
+    pub fn IsTextureReady(texture: Texture2D) -> bool_;
+}
+extern "C" {
+    pub fn IsRenderTextureReady(target: RenderTexture2D) -> bool_;
+}
 extern "C" {
     pub fn UpdateTexture(texture: Texture2D, pixels: *const ::std::os::raw::c_void);
 }
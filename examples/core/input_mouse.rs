@@ -4,7 +4,7 @@ const SCREEN_WIDTH: usize = 800;
 const SCREEN_HEIGHT: usize = 450;
 
 fn main() -> dioteko::Result<()> {
-    let window = WindowBuilder::new(
+    let mut window = WindowBuilder::new(
         SCREEN_WIDTH,
         SCREEN_HEIGHT,
         c"raylib [core] example - input mouse",
@@ -36,7 +36,7 @@ fn main() -> dioteko::Result<()> {
         }
 
         {
-            let painter = Painter::new();
+            let painter = window.begin_drawing();
 
             painter.clear_background(RAYWHITE);
             painter.draw_circle_v(ball_position, 50., ball_color);
@@ -0,0 +1,146 @@
+//! Application Runner
+//!
+//! `run` drives an `App` with a fixed simulation step. `App::fixed_update` is called
+//! a deterministic number of times for the elapsed time regardless of the refresh
+//! rate of the monitor, and `App::draw` receives how far the simulation is between
+//! the last step and the next one so that the rendering can be interpolated.
+
+use crate::core::utility::time;
+use crate::core::window::{Window, WindowBuilder};
+use crate::error;
+use crate::painter::Painter;
+
+pub trait App {
+    /// Time between two simulation steps in seconds. It must be positive.
+    const FIXED_TIMESTEP: f64 = 1.0 / 60.0;
+    /// Upper bound of the simulation steps in one frame. Any time exceeding it is
+    /// dropped so that a slow frame can not make the next ones even slower.
+    /// It must be positive.
+    const MAX_FIXED_STEPS: u32 = 8;
+
+    /// Called once after the window is built
+    fn init(&mut self, _window: &mut Window) {}
+
+    /// Called once per frame with the elapsed time in seconds
+    fn update(&mut self, _window: &mut Window, _dt: f64) {}
+
+    /// Called zero or more times per frame with `FIXED_TIMESTEP`
+    fn fixed_update(&mut self, _dt: f64) {}
+
+    /// Called once per frame. `alpha` in `[0, 1)` is the fraction of the next
+    /// simulation step that has already been elapsed.
+    fn draw(&mut self, painter: &Painter, alpha: f64);
+
+    /// Stop running when it returns true, even if the window is not closed
+    fn should_exit(&self) -> bool {
+        false
+    }
+}
+
+/// Build the window and run `app` until the window should close
+///
+/// # Panics
+///
+/// Panics before building the window if `A::FIXED_TIMESTEP` or `A::MAX_FIXED_STEPS`
+/// is not positive.
+pub fn run<A: App>(builder: WindowBuilder, mut app: A) -> error::Result<A> {
+    let mut stepper = FixedStepper::new(A::FIXED_TIMESTEP, A::MAX_FIXED_STEPS);
+
+    let mut window = builder.build()?;
+    app.init(&mut window);
+
+    let mut previous = time::get_time();
+
+    while !window.should_close() && !app.should_exit() {
+        let now = time::get_time();
+        let dt = now - previous;
+        previous = now;
+
+        app.update(&mut window, dt);
+        for _ in 0..stepper.advance(dt) {
+            app.fixed_update(A::FIXED_TIMESTEP);
+        }
+
+        {
+            let painter = window.begin_drawing();
+            app.draw(&painter, stepper.alpha());
+        }
+
+        #[cfg(feature = "custom-frame-control")]
+        {
+            window.swap_screen_buffer();
+            window.poll_input_events();
+        }
+    }
+
+    Ok(app)
+}
+
+/// Accumulates the frame times and splits them into fixed steps.
+///
+/// `run` uses it for `App`, and it can drive a custom main loop in the same way.
+#[derive(Debug, Clone)]
+pub struct FixedStepper {
+    step: f64,
+    max_steps: u32,
+    accumulator: f64,
+}
+
+impl FixedStepper {
+    /// # Panics
+    ///
+    /// Panics if `step` is not a positive finite number or `max_steps` is zero, since
+    /// the simulation could never advance.
+    pub fn new(step: f64, max_steps: u32) -> Self {
+        assert!(
+            step.is_finite() && step > 0.0,
+            "FIXED_TIMESTEP must be positive, but it is {step}"
+        );
+        assert!(max_steps > 0, "MAX_FIXED_STEPS must be positive");
+
+        Self {
+            step,
+            max_steps,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Add the elapsed time in seconds, and returns how many steps should be
+    /// simulated for this frame. It is at most `max_steps`, and the time exceeding it
+    /// is dropped.
+    pub fn advance(&mut self, dt: f64) -> u32 {
+        // A negative or broken time stands still
+        if dt.is_finite() && dt > 0.0 {
+            self.accumulator += dt;
+        }
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+
+        // Drops the time which could not be simulated
+        if steps == self.max_steps {
+            self.accumulator %= self.step;
+        }
+
+        steps
+    }
+
+    /// The fraction of the next step which has already been elapsed, in `[0, 1)`
+    #[inline]
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / self.step
+    }
+
+    #[inline]
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    #[inline]
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+}
@@ -0,0 +1,400 @@
+//! Input Action Module
+//!
+//! Game code asks for logical actions such as "jump" or "move_x" instead of concrete
+//! keys. Each action is bound to one or more inputs, and the bindings can be changed
+//! at runtime and stored in a text config file:
+//!
+//! ```text
+//! # action = binding, binding, ...
+//! jump   = Space, Gamepad:RightFaceDown
+//! move_x = D, -A, GamepadAxis:LeftX
+//! walk_x = 0.5*Right, -0.5*Left
+//! save   = LeftControl+S
+//! zoom   = MouseWheel
+//! ```
+//!
+//! A leading `-` negates the contribution of the binding to `ActionMap::axis`, and a
+//! leading `scale*` multiplies it by `scale`.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::core::gamepad::{Gamepad, GamepadAxis, GamepadButton};
+use crate::core::keyboard::Key;
+use crate::core::mouse::{self, MouseButton};
+use crate::error::{self, DiotekoErr};
+
+/// A physical input
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Input {
+    /// Every key must be held, and the last one triggers the input (e.g. `LeftControl+S`)
+    Keys(Vec<Key>),
+    Mouse(MouseButton),
+    /// The vertical movement of the mouse wheel
+    MouseWheel,
+    GamepadButton(GamepadButton),
+    GamepadAxis(GamepadAxis),
+}
+
+/// An input bound to an action
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Binding {
+    pub input: Input,
+    /// The multiplier of the contribution to `ActionMap::axis`
+    pub scale: f32,
+}
+
+/// A set of named actions and their bindings
+#[derive(Debug, Clone)]
+pub struct ActionMap {
+    actions: HashMap<String, Vec<Binding>>,
+    gamepad: Gamepad,
+    dead_zone: f32,
+}
+
+impl Input {
+    fn is_pressed(&self, gamepad: Gamepad) -> bool {
+        match self {
+            Self::Keys(keys) => match keys.split_last() {
+                Some((last, modifiers)) => {
+                    modifiers.iter().all(|key| key.is_down()) && last.is_pressed()
+                }
+                None => false,
+            },
+            Self::Mouse(button) => button.is_pressed(),
+            Self::MouseWheel => mouse::get_mouse_wheel_move() != 0.0,
+            Self::GamepadButton(button) => gamepad.is_pressed(*button),
+            Self::GamepadAxis(_) => false,
+        }
+    }
+
+    fn is_down(&self, gamepad: Gamepad, dead_zone: f32) -> bool {
+        self.value(gamepad, dead_zone) != 0.0
+    }
+
+    fn is_released(&self, gamepad: Gamepad) -> bool {
+        match self {
+            Self::Keys(keys) => keys.last().is_some_and(|key| key.is_released()),
+            Self::Mouse(button) => button.is_released(),
+            Self::MouseWheel => false,
+            Self::GamepadButton(button) => gamepad.is_released(*button),
+            Self::GamepadAxis(_) => false,
+        }
+    }
+
+    fn value(&self, gamepad: Gamepad, dead_zone: f32) -> f32 {
+        let held = |down: bool| if down { 1.0 } else { 0.0 };
+
+        match self {
+            Self::Keys(keys) => held(!keys.is_empty() && keys.iter().all(|key| key.is_down())),
+            Self::Mouse(button) => held(button.is_down()),
+            Self::MouseWheel => mouse::get_mouse_wheel_move(),
+            Self::GamepadButton(button) => held(gamepad.is_down(*button)),
+            Self::GamepadAxis(axis) => gamepad.axis_with_dead_zone(*axis, dead_zone),
+        }
+    }
+}
+
+impl Binding {
+    #[inline]
+    pub fn new(input: Input) -> Self {
+        Self { input, scale: 1.0 }
+    }
+
+    #[inline]
+    pub fn negated(input: Input) -> Self {
+        Self { input, scale: -1.0 }
+    }
+}
+
+impl From<Input> for Binding {
+    #[inline]
+    fn from(input: Input) -> Self {
+        Self::new(input)
+    }
+}
+
+impl From<Key> for Binding {
+    #[inline]
+    fn from(key: Key) -> Self {
+        Self::new(Input::Keys(vec![key]))
+    }
+}
+
+impl From<MouseButton> for Binding {
+    #[inline]
+    fn from(button: MouseButton) -> Self {
+        Self::new(Input::Mouse(button))
+    }
+}
+
+impl From<GamepadButton> for Binding {
+    #[inline]
+    fn from(button: GamepadButton) -> Self {
+        Self::new(Input::GamepadButton(button))
+    }
+}
+
+impl From<GamepadAxis> for Binding {
+    #[inline]
+    fn from(axis: GamepadAxis) -> Self {
+        Self::new(Input::GamepadAxis(axis))
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keys(keys) => {
+                for (i, key) in keys.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "+")?;
+                    }
+                    write!(f, "{key}")?;
+                }
+                Ok(())
+            }
+            Self::Mouse(button) => write!(f, "Mouse:{button}"),
+            Self::MouseWheel => write!(f, "MouseWheel"),
+            Self::GamepadButton(button) => write!(f, "Gamepad:{button}"),
+            Self::GamepadAxis(axis) => write!(f, "GamepadAxis:{axis}"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = DiotekoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_err = |_| DiotekoErr::ParseBindingErr(s.to_string());
+
+        if s.eq_ignore_ascii_case("MouseWheel") {
+            return Ok(Self::MouseWheel);
+        }
+
+        match s.split_once(':') {
+            Some((kind, name)) if kind.eq_ignore_ascii_case("Mouse") => {
+                name.trim().parse().map(Self::Mouse).map_err(parse_err)
+            }
+            Some((kind, name)) if kind.eq_ignore_ascii_case("Gamepad") => name
+                .trim()
+                .parse()
+                .map(Self::GamepadButton)
+                .map_err(parse_err),
+            Some((kind, name)) if kind.eq_ignore_ascii_case("GamepadAxis") => name
+                .trim()
+                .parse()
+                .map(Self::GamepadAxis)
+                .map_err(parse_err),
+            Some(_) => Err(DiotekoErr::ParseBindingErr(s.to_string())),
+            None => s
+                .split('+')
+                .map(|key| key.trim().parse::<Key>())
+                .collect::<error::Result<Vec<_>>>()
+                .map(Self::Keys)
+                .map_err(parse_err),
+        }
+    }
+}
+
+// The scales `1` and `-1` are written as `D` and `-D`, and the others as `0.5*D`
+impl Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 1.0 {
+            write!(f, "{}", self.input)
+        } else if self.scale == -1.0 {
+            write!(f, "-{}", self.input)
+        } else {
+            write!(f, "{}*{}", self.scale, self.input)
+        }
+    }
+}
+
+impl FromStr for Binding {
+    type Err = DiotekoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some((scale, input)) = s.split_once('*') {
+            let scale = scale
+                .trim()
+                .parse()
+                .map_err(|_| DiotekoErr::ParseBindingErr(s.to_string()))?;
+            return Ok(Self {
+                input: input.parse()?,
+                scale,
+            });
+        }
+
+        match s.strip_prefix('-') {
+            Some(input) => input.parse().map(Self::negated),
+            None => s.parse().map(Self::new),
+        }
+    }
+}
+
+impl Default for ActionMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActionMap {
+    pub fn new() -> Self {
+        Self {
+            actions: HashMap::new(),
+            gamepad: Gamepad(0),
+            dead_zone: 0.1,
+        }
+    }
+
+    /// Set which gamepad is used by the gamepad bindings
+    #[inline]
+    pub fn set_gamepad(&mut self, gamepad: Gamepad) {
+        self.gamepad = gamepad;
+    }
+
+    /// Set the dead zone of the gamepad axis bindings
+    #[inline]
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        self.dead_zone = dead_zone;
+    }
+
+    /// Add a binding to the action. The action is created if it does not exist
+    pub fn bind(&mut self, action: &str, binding: impl Into<Binding>) {
+        self.actions
+            .entry(action.to_string())
+            .or_default()
+            .push(binding.into());
+    }
+
+    /// Replace every binding of the action
+    pub fn rebind(&mut self, action: &str, bindings: Vec<Binding>) {
+        self.actions.insert(action.to_string(), bindings);
+    }
+
+    /// Remove every binding of the action while keeping the action itself
+    pub fn unbind(&mut self, action: &str) {
+        if let Some(bindings) = self.actions.get_mut(action) {
+            bindings.clear();
+        }
+    }
+
+    /// Remove the action and returns its bindings
+    pub fn remove(&mut self, action: &str) -> Option<Vec<Binding>> {
+        self.actions.remove(action)
+    }
+
+    pub fn bindings(&self, action: &str) -> &[Binding] {
+        self.actions.get(action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterate over the names of the actions
+    pub fn actions(&self) -> impl Iterator<Item = &str> {
+        self.actions.keys().map(String::as_str)
+    }
+
+    /// Check whether any binding of the action has been pressed in this frame
+    pub fn pressed(&self, action: &str) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| binding.input.is_pressed(self.gamepad))
+    }
+
+    /// Check whether any binding of the action is being held
+    pub fn down(&self, action: &str) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| binding.input.is_down(self.gamepad, self.dead_zone))
+    }
+
+    /// Check whether any binding of the action has been released in this frame
+    pub fn released(&self, action: &str) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| binding.input.is_released(self.gamepad))
+    }
+
+    /// Get the sum of every binding of the action, clamped to [-1.0, 1.0]
+    pub fn axis(&self, action: &str) -> f32 {
+        self.bindings(action)
+            .iter()
+            .map(|binding| binding.scale * binding.input.value(self.gamepad, self.dead_zone))
+            .sum::<f32>()
+            .clamp(-1.0, 1.0)
+    }
+
+    /// Load the bindings from a config file, replacing the bindings of every action
+    /// in the file
+    pub fn load(&mut self, path: impl AsRef<Path>) -> error::Result<()> {
+        let path = path.as_ref();
+        let config = fs::read_to_string(path).map_err(|err| DiotekoErr::load_file(path, err))?;
+        let parsed: ActionMap = config.parse()?;
+        self.actions.extend(parsed.actions);
+
+        Ok(())
+    }
+
+    /// Save the bindings to a config file
+    pub fn save(&self, path: impl AsRef<Path>) -> error::Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|err| DiotekoErr::save_file(path, err))
+    }
+}
+
+impl Display for ActionMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Sorts the actions so that saved files are stable
+        let mut actions = self.actions.iter().collect::<Vec<_>>();
+        actions.sort_by(|lhs, rhs| lhs.0.cmp(rhs.0));
+
+        for (action, bindings) in actions {
+            write!(f, "{action} =")?;
+            for (i, binding) in bindings.iter().enumerate() {
+                let sep = if i > 0 { "," } else { "" };
+                write!(f, "{sep} {binding}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ActionMap {
+    type Err = DiotekoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::new();
+
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parse_err = || DiotekoErr::ParseBindingErr(line.to_string());
+            let (action, bindings) = line.split_once('=').ok_or_else(parse_err)?;
+            let action = action.trim();
+            if action.is_empty() {
+                return Err(parse_err());
+            }
+
+            let bindings = bindings
+                .split(',')
+                .map(str::trim)
+                .filter(|binding| !binding.is_empty())
+                .map(str::parse)
+                .collect::<error::Result<Vec<_>>>()?;
+            map.rebind(action, bindings);
+        }
+
+        Ok(map)
+    }
+}
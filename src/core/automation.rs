@@ -0,0 +1,269 @@
+//! Automation Module
+//!
+//! raylib can record every input event with the frame it happened in, and play
+//! them back later. This makes it possible to reproduce a session deterministically:
+//! record with `AutomationEventList::record`, store it with `export`, and replay a
+//! loaded list with `AutomationPlayer::update` once per frame.
+
+use std::ffi::CStr;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::error::{self, DiotekoErr};
+use crate::ffi;
+
+/// A recorded input event
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy)]
+pub struct AutomationEvent(ffi::AutomationEvent);
+
+/// An owned list of automation events
+pub struct AutomationEventList {
+    // Boxed so that the pointer given to raylib stays valid when the list moves
+    list: Box<ffi::AutomationEventList>,
+}
+
+/// Records input events into a list while it is alive
+pub struct AutomationRecorder<'a> {
+    list: &'a mut AutomationEventList,
+}
+
+// The list raylib records into, or null. raylib keeps a single global pointer, so
+// whoever unregisters a list must check that it is still the registered one.
+static REGISTERED_LIST: AtomicPtr<ffi::AutomationEventList> = AtomicPtr::new(ptr::null_mut());
+
+/// Plays the events of a list at their recorded frames
+pub struct AutomationPlayer<'a> {
+    events: &'a [AutomationEvent],
+    frame: u32,
+    next: usize,
+}
+
+impl AutomationEvent {
+    /// The frame in which the event was recorded, counted from the start of recording
+    #[inline]
+    pub fn frame(&self) -> u32 {
+        self.0.frame
+    }
+
+    /// The raw raylib event type (e.g. `INPUT_KEY_DOWN`)
+    #[inline]
+    pub fn event_type(&self) -> u32 {
+        self.0.type_
+    }
+
+    /// The raw parameters of the event. Their meaning depends on the event type
+    #[inline]
+    pub fn params(&self) -> [i32; 4] {
+        self.0.params
+    }
+
+    /// Inject the event into the current input state
+    pub fn play(self) {
+        // SAFETY: ffi
+        unsafe { ffi::PlayAutomationEvent(self.0) }
+    }
+}
+
+impl Default for AutomationEventList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutomationEventList {
+    /// Make an empty list with raylib's default capacity
+    pub fn new() -> Self {
+        // SAFETY: ffi
+        // SAFETY: with a null file name, raylib only allocates the events
+        Self {
+            list: Box::new(unsafe { ffi::LoadAutomationEventList(ptr::null()) }),
+        }
+    }
+
+    /// Load a list from a file exported with `export`
+    pub fn load(filename: &CStr) -> error::Result<Self> {
+        // SAFETY: ffi
+        if !unsafe { to_bool!(ffi::FileExists(filename.as_ptr())) } {
+            return Err(DiotekoErr::load_file(
+                error::path_of(filename),
+                "the file does not exist",
+            ));
+        }
+
+        // SAFETY: ffi
+        Ok(Self {
+            list: Box::new(unsafe { ffi::LoadAutomationEventList(filename.as_ptr()) }),
+        })
+    }
+
+    /// Export the list into a file. Files ending with `.rae` are stored as binary,
+    /// and the others as text.
+    pub fn export(&self, filename: &CStr) -> error::Result<()> {
+        // SAFETY: ffi
+        if unsafe {
+            to_bool!(ffi::ExportAutomationEventList(
+                *self.list,
+                filename.as_ptr()
+            ))
+        } {
+            Ok(())
+        } else {
+            Err(DiotekoErr::save_file(
+                error::path_of(filename),
+                "could not write the file",
+            ))
+        }
+    }
+
+    /// Start recording. Recording stops when the returned recorder or the list is
+    /// dropped, and when another list starts recording.
+    pub fn record(&mut self) -> AutomationRecorder<'_> {
+        AutomationRecorder::new(self)
+    }
+
+    /// Make a player which replays the events from the first frame
+    pub fn play(&self) -> AutomationPlayer<'_> {
+        AutomationPlayer {
+            events: self.events(),
+            frame: 0,
+            next: 0,
+        }
+    }
+
+    /// Get every event in the recorded order
+    pub fn events(&self) -> &[AutomationEvent] {
+        if self.list.events.is_null() {
+            return &[];
+        }
+
+        // SAFETY: raylib keeps `count` initialized events in `events`, and
+        // AutomationEvent is repr(transparent) over ffi::AutomationEvent
+        unsafe {
+            std::slice::from_raw_parts(
+                self.list.events as *const AutomationEvent,
+                self.list.count as usize,
+            )
+        }
+    }
+
+    /// Iterate over the events recorded in the given frame
+    pub fn events_at(&self, frame: u32) -> impl Iterator<Item = &AutomationEvent> {
+        self.events()
+            .iter()
+            .filter(move |event| event.frame() == frame)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.list.count as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.list.count == 0
+    }
+
+    /// The maximum number of events the list can hold
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.list.capacity as usize
+    }
+}
+
+impl<'a> IntoIterator for &'a AutomationEventList {
+    type Item = &'a AutomationEvent;
+    type IntoIter = std::slice::Iter<'a, AutomationEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events().iter()
+    }
+}
+
+impl AutomationEventList {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut ffi::AutomationEventList {
+        &mut *self.list
+    }
+
+    // Stops recording if raylib records into this list, so that raylib never
+    // writes through a dangling pointer. A forgotten recorder can not prevent it.
+    fn unregister(&mut self) {
+        let list = self.as_mut_ptr();
+        if REGISTERED_LIST
+            .compare_exchange(list, ptr::null_mut(), Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            // SAFETY: ffi
+            unsafe {
+                ffi::StopAutomationEventRecording();
+                ffi::SetAutomationEventList(ptr::null_mut());
+            }
+        }
+    }
+}
+
+impl Drop for AutomationEventList {
+    fn drop(&mut self) {
+        self.unregister();
+
+        // SAFETY: ffi
+        // SAFETY: the events were allocated by LoadAutomationEventList, and raylib no
+        // longer refers to the list
+        unsafe { ffi::UnloadAutomationEventList(self.as_mut_ptr()) }
+    }
+}
+
+impl<'a> AutomationRecorder<'a> {
+    fn new(list: &'a mut AutomationEventList) -> Self {
+        let ptr = list.as_mut_ptr();
+        REGISTERED_LIST.store(ptr, Ordering::Release);
+
+        // SAFETY: ffi
+        // SAFETY: the list is boxed, and it is unregistered when it drops, so the
+        // pointer raylib keeps is valid until recording stops
+        unsafe {
+            ffi::StopAutomationEventRecording();
+            ffi::SetAutomationEventList(ptr);
+            ffi::SetAutomationEventBaseFrame(0);
+            ffi::StartAutomationEventRecording();
+        }
+
+        Self { list }
+    }
+}
+
+impl Drop for AutomationRecorder<'_> {
+    fn drop(&mut self) {
+        self.list.unregister();
+    }
+}
+
+impl AutomationPlayer<'_> {
+    /// Play every event of the current frame and advance to the next frame.
+    /// This should be called once per frame, before the input is handled.
+    pub fn update(&mut self) {
+        while let Some(event) = self.events.get(self.next) {
+            if event.frame() > self.frame {
+                break;
+            }
+
+            event.play();
+            self.next += 1;
+        }
+
+        self.frame += 1;
+    }
+
+    /// The frame which will be played in the next `update`
+    #[inline]
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Check whether every event has been played
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+}
@@ -0,0 +1,30 @@
+use std::ffi::{CStr, CString};
+
+use crate::error;
+use crate::ffi;
+
+/// Get the UTF-8 text of the system clipboard.
+/// Returns `None` if the clipboard is empty or does not contain a text.
+pub fn get() -> Option<String> {
+    // SAFETY: ffi
+    let text = unsafe { ffi::GetClipboardText() };
+    if text.is_null() {
+        return None;
+    }
+
+    // SAFETY: raylib returns a nul-terminated string owned by GLFW, and it is valid
+    // until the next clipboard call. Copy it right now.
+    let text = unsafe { CStr::from_ptr(text) };
+    Some(text.to_string_lossy().into_owned())
+}
+
+/// Set the UTF-8 text of the system clipboard.
+/// Fails if `text` contains a nul character.
+pub fn set(text: &str) -> error::Result<()> {
+    let text = CString::new(text)?;
+
+    // SAFETY: ffi
+    unsafe { ffi::SetClipboardText(text.as_ptr()) }
+
+    Ok(())
+}
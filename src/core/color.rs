@@ -1,7 +1,11 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
 use crate::core::linalg::{Vector3, Vector4};
+use crate::error::DiotekoErr;
 use crate::ffi;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Color(pub u8, pub u8, pub u8, pub u8);
 
 impl From<ffi::Color> for Color {
@@ -70,6 +74,83 @@ impl Color {
     }
 }
 
+// Formatted as "#RRGGBBAA"
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.0, self.1, self.2, self.3
+        )
+    }
+}
+
+// Parses "#RRGGBBAA", or "#RRGGBB" for an opaque color
+impl FromStr for Color {
+    type Err = DiotekoErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_err = || DiotekoErr::ParseColorErr(s.to_string());
+
+        let hex = s.strip_prefix('#').ok_or_else(parse_err)?;
+        if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(parse_err());
+        }
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| parse_err());
+        let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+        Ok(Self(channel(0)?, channel(2)?, channel(4)?, alpha))
+    }
+}
+
+// Serialized as `[r, g, b, a]`. Human-readable formats also accept "#RRGGBBAA".
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&[self.0, self.1, self.2, self.3], serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an array of 4 bytes or a \"#RRGGBBAA\" string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Color, E> {
+                s.parse().map_err(E::custom)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Color, A::Error> {
+                let mut channels = [0; 4];
+                for (i, channel) in channels.iter_mut().enumerate() {
+                    *channel = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+
+                let [r, g, b, a] = channels;
+                Ok(Color(r, g, b, a))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColorVisitor)
+        } else {
+            deserializer.deserialize_tuple(4, ColorVisitor)
+        }
+    }
+}
+
 // Predefined Colors
 pub const LIGHTGRAY: Color = Color(200, 200, 200, 255); // Light Gray
 pub const GRAY: Color = Color(130, 130, 130, 255); // Gray
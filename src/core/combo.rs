@@ -0,0 +1,345 @@
+//! Combo Module
+//!
+//! A `Chord` is a set of buttons which are held together, such as `Ctrl+Shift+S`.
+//! A `SequenceDetector` matches chords pressed one after another, where each step
+//! must follow the previous one within a maximum gap. Double taps, fighting game
+//! motions and cheat codes are all sequences:
+//!
+//! ```no_run
+//! use dioteko::prelude::*;
+//!
+//! let mut dash = SequenceDetector::new([Key::D, Key::D]).with_max_gap(0.25);
+//! // Once per frame
+//! if dash.update() {
+//!     // dash to the right
+//! }
+//! ```
+
+use crate::core::input_state::InputState;
+use crate::core::keyboard::Key;
+use crate::core::mouse::MouseButton;
+use crate::core::utility::time;
+
+/// A button which can take part in a chord
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Button {
+    Key(Key),
+    Mouse(MouseButton),
+}
+
+/// Buttons which are held together
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chord {
+    buttons: Vec<Button>,
+}
+
+/// Detects a sequence of chords pressed within a maximum gap of time
+///
+/// Only the buttons which appear in the sequence are watched. A step is entered
+/// when the watched buttons being held become exactly its chord, so the motion
+/// `Down, Down+Right, Right` is entered by pressing Right and then releasing Down.
+/// A step which does not continue the sequence falls back to the longest matched
+/// prefix, so `Up, Up, Up, Down` still completes `Up, Up, Down`.
+#[derive(Debug, Clone)]
+pub struct SequenceDetector {
+    steps: Vec<Chord>,
+    // Every button of the steps without duplicates
+    buttons: Vec<Button>,
+    // failure[i] is the length of the longest proper prefix of steps[..=i] which is
+    // also its suffix (the KMP failure function)
+    failure: Vec<usize>,
+    max_gap: f64,
+    progress: usize,
+    last_step_time: f64,
+}
+
+impl Button {
+    pub fn is_pressed(self) -> bool {
+        match self {
+            Self::Key(key) => key.is_pressed(),
+            Self::Mouse(button) => button.is_pressed(),
+        }
+    }
+
+    pub fn is_down(self) -> bool {
+        match self {
+            Self::Key(key) => key.is_down(),
+            Self::Mouse(button) => button.is_down(),
+        }
+    }
+
+    pub fn is_pressed_in(self, input: &InputState) -> bool {
+        match self {
+            Self::Key(key) => input.is_key_pressed(key),
+            Self::Mouse(button) => input.is_mouse_button_pressed(button),
+        }
+    }
+
+    pub fn is_down_in(self, input: &InputState) -> bool {
+        match self {
+            Self::Key(key) => input.is_key_down(key),
+            Self::Mouse(button) => input.is_mouse_button_down(button),
+        }
+    }
+
+    // Whether the button was held in the previous frame
+    fn was_down(self) -> bool {
+        let released = match self {
+            Self::Key(key) => key.is_released(),
+            Self::Mouse(button) => button.is_released(),
+        };
+        (self.is_down() && !self.is_pressed()) || released
+    }
+
+    fn was_down_in(self, input: &InputState) -> bool {
+        let released = match self {
+            Self::Key(key) => input.is_key_released(key),
+            Self::Mouse(button) => input.is_mouse_button_released(button),
+        };
+        (self.is_down_in(input) && !self.is_pressed_in(input)) || released
+    }
+}
+
+impl From<Key> for Button {
+    #[inline]
+    fn from(key: Key) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for Button {
+    #[inline]
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl Chord {
+    pub fn new<B: Into<Button>>(buttons: impl IntoIterator<Item = B>) -> Self {
+        let mut chord = Self {
+            buttons: Vec::new(),
+        };
+        for button in buttons {
+            chord = chord.with(button);
+        }
+        chord
+    }
+
+    /// Add a button to the chord
+    #[inline]
+    pub fn with(mut self, button: impl Into<Button>) -> Self {
+        let button = button.into();
+        if !self.buttons.contains(&button) {
+            self.buttons.push(button);
+        }
+        self
+    }
+
+    #[inline]
+    pub fn buttons(&self) -> &[Button] {
+        &self.buttons
+    }
+
+    #[inline]
+    pub fn contains(&self, button: Button) -> bool {
+        self.buttons.contains(&button)
+    }
+
+    /// Check whether the chord has been completed in this frame, that is, every
+    /// button is held now but not in the previous frame.
+    /// The buttons can be pressed in any order.
+    pub fn is_pressed(&self) -> bool {
+        self.is_pressed_by(|button| button.is_down(), |button| button.was_down())
+    }
+
+    /// Check whether every button is being held
+    pub fn is_down(&self) -> bool {
+        !self.buttons.is_empty() && self.buttons.iter().all(|button| button.is_down())
+    }
+
+    /// Same as `is_pressed`, but reads a captured input state
+    pub fn is_pressed_in(&self, input: &InputState) -> bool {
+        self.is_pressed_by(
+            |button| button.is_down_in(input),
+            |button| button.was_down_in(input),
+        )
+    }
+
+    /// Same as `is_down`, but reads a captured input state
+    pub fn is_down_in(&self, input: &InputState) -> bool {
+        !self.buttons.is_empty() && self.buttons.iter().all(|button| button.is_down_in(input))
+    }
+
+    fn is_pressed_by(
+        &self,
+        is_down: impl Fn(Button) -> bool,
+        was_down: impl Fn(Button) -> bool,
+    ) -> bool {
+        !self.buttons.is_empty()
+            && self.buttons.iter().all(|&button| is_down(button))
+            && !self.buttons.iter().all(|&button| was_down(button))
+    }
+
+    // Same buttons regardless of the order
+    fn same_buttons(&self, other: &Self) -> bool {
+        self.buttons.len() == other.buttons.len()
+            && self.buttons.iter().all(|&button| other.contains(button))
+    }
+}
+
+impl From<Key> for Chord {
+    #[inline]
+    fn from(key: Key) -> Self {
+        Self::new([key])
+    }
+}
+
+impl From<MouseButton> for Chord {
+    #[inline]
+    fn from(button: MouseButton) -> Self {
+        Self::new([button])
+    }
+}
+
+impl From<Button> for Chord {
+    #[inline]
+    fn from(button: Button) -> Self {
+        Self::new([button])
+    }
+}
+
+impl SequenceDetector {
+    /// The default maximum gap between two steps in seconds
+    pub const DEFAULT_MAX_GAP: f64 = 0.5;
+
+    pub fn new<C: Into<Chord>>(steps: impl IntoIterator<Item = C>) -> Self {
+        let steps = steps.into_iter().map(Into::into).collect::<Vec<Chord>>();
+
+        let mut buttons = Vec::new();
+        for &button in steps.iter().flat_map(Chord::buttons) {
+            if !buttons.contains(&button) {
+                buttons.push(button);
+            }
+        }
+
+        Self {
+            failure: failure_table(&steps),
+            steps,
+            buttons,
+            max_gap: Self::DEFAULT_MAX_GAP,
+            progress: 0,
+            last_step_time: 0.0,
+        }
+    }
+
+    /// Set the maximum time in seconds allowed between two steps
+    #[inline]
+    pub fn with_max_gap(mut self, seconds: f64) -> Self {
+        self.max_gap = seconds;
+        self
+    }
+
+    #[inline]
+    pub fn steps(&self) -> &[Chord] {
+        &self.steps
+    }
+
+    #[inline]
+    pub fn max_gap(&self) -> f64 {
+        self.max_gap
+    }
+
+    /// The number of steps matched so far
+    #[inline]
+    pub fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// Forget the steps matched so far
+    #[inline]
+    pub fn reset(&mut self) {
+        self.progress = 0;
+    }
+
+    /// Advance the sequence with the input of the current frame.
+    /// Returns true in the frame the last step is matched.
+    ///
+    /// This should be called once per frame.
+    pub fn update(&mut self) -> bool {
+        self.advance(
+            time::get_time(),
+            |button| button.is_down(),
+            |button| button.was_down(),
+        )
+    }
+
+    /// Same as `update`, but reads a captured input state and takes the current
+    /// time in seconds, so that sequences can be driven without a window
+    pub fn update_from(&mut self, input: &InputState, now: f64) -> bool {
+        self.advance(
+            now,
+            |button| button.is_down_in(input),
+            |button| button.was_down_in(input),
+        )
+    }
+
+    fn advance(
+        &mut self,
+        now: f64,
+        is_down: impl Fn(Button) -> bool,
+        was_down: impl Fn(Button) -> bool,
+    ) -> bool {
+        if self.progress > 0 && now - self.last_step_time > self.max_gap {
+            self.progress = 0;
+        }
+
+        // The step whose chord has just been entered, if any
+        let Some(entered) = self.steps.iter().position(|chord| {
+            !chord.buttons.is_empty()
+                && self.holds_exactly(chord, &is_down)
+                && !self.holds_exactly(chord, &was_down)
+        }) else {
+            return false;
+        };
+        let entered = &self.steps[entered];
+
+        while self.progress > 0 && !self.steps[self.progress].same_buttons(entered) {
+            self.progress = self.failure[self.progress - 1];
+        }
+        if self.steps[self.progress].same_buttons(entered) {
+            self.progress += 1;
+            self.last_step_time = now;
+        }
+
+        if self.progress == self.steps.len() {
+            self.progress = 0;
+            return true;
+        }
+        false
+    }
+
+    // Whether the held buttons of the sequence are exactly the ones of `chord`
+    fn holds_exactly(&self, chord: &Chord, held: impl Fn(Button) -> bool) -> bool {
+        self.buttons
+            .iter()
+            .all(|&button| held(button) == chord.contains(button))
+    }
+}
+
+fn failure_table(steps: &[Chord]) -> Vec<usize> {
+    let mut failure = vec![0; steps.len()];
+    let mut matched = 0;
+
+    for i in 1..steps.len() {
+        while matched > 0 && !steps[i].same_buttons(&steps[matched]) {
+            matched = failure[matched - 1];
+        }
+        if steps[i].same_buttons(&steps[matched]) {
+            matched += 1;
+        }
+        failure[i] = matched;
+    }
+
+    failure
+}
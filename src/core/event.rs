@@ -0,0 +1,139 @@
+use std::ffi::CStr;
+use std::path::PathBuf;
+
+use crate::core::keyboard::Key;
+use crate::core::linalg::Vector2;
+use crate::core::mouse::{self, MouseButton};
+use crate::ffi;
+
+/// An input or window event which happened in the current frame
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    KeyDown(Key),
+    KeyUp(Key),
+    /// A held key is repeated by the OS
+    KeyRepeat(Key),
+    /// A typed unicode character
+    Char(char),
+    MouseMove {
+        position: Vector2,
+        delta: Vector2,
+    },
+    MouseButton {
+        button: MouseButton,
+        pressed: bool,
+    },
+    Wheel(Vector2),
+    WindowResized {
+        width: usize,
+        height: usize,
+    },
+    FocusChanged(bool),
+    FilesDropped(Vec<PathBuf>),
+}
+
+// Collects the events of the current frame. `focused` keeps the focus state of the
+// previous call to detect focus changes.
+pub(crate) fn poll(focused: &mut bool) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    // SAFETY: ffi
+    unsafe {
+        if to_bool!(ffi::IsWindowResized()) {
+            events.push(Event::WindowResized {
+                width: ffi::GetScreenWidth() as usize,
+                height: ffi::GetScreenHeight() as usize,
+            });
+        }
+
+        let now_focused = to_bool!(ffi::IsWindowFocused());
+        if now_focused != *focused {
+            *focused = now_focused;
+            events.push(Event::FocusChanged(now_focused));
+        }
+    }
+
+    // The key queue keeps the pressing order, so it is drained instead of scanning
+    loop {
+        // SAFETY: ffi
+        let key = unsafe { ffi::GetKeyPressed() };
+        if key == 0 {
+            break;
+        }
+        if let Ok(key) = Key::try_from(key) {
+            events.push(Event::KeyDown(key));
+        }
+    }
+
+    for &key in Key::ALL {
+        if key.is_pressed_repeat() {
+            events.push(Event::KeyRepeat(key));
+        }
+        if key.is_released() {
+            events.push(Event::KeyUp(key));
+        }
+    }
+
+    loop {
+        // SAFETY: ffi
+        let ch = unsafe { ffi::GetCharPressed() };
+        if ch == 0 {
+            break;
+        }
+        if let Some(ch) = char::from_u32(ch as u32) {
+            events.push(Event::Char(ch));
+        }
+    }
+
+    let delta = mouse::get_mouse_delta();
+    if delta.x != 0.0 || delta.y != 0.0 {
+        events.push(Event::MouseMove {
+            position: mouse::get_mouse_position(),
+            delta,
+        });
+    }
+
+    for &button in MouseButton::ALL {
+        if button.is_pressed() {
+            events.push(Event::MouseButton {
+                button,
+                pressed: true,
+            });
+        }
+        if button.is_released() {
+            events.push(Event::MouseButton {
+                button,
+                pressed: false,
+            });
+        }
+    }
+
+    let wheel = mouse::get_mouse_wheel_move_v();
+    if wheel.x != 0.0 || wheel.y != 0.0 {
+        events.push(Event::Wheel(wheel));
+    }
+
+    // SAFETY: ffi
+    if unsafe { to_bool!(ffi::IsFileDropped()) } {
+        events.push(Event::FilesDropped(load_dropped_files()));
+    }
+
+    events
+}
+
+fn load_dropped_files() -> Vec<PathBuf> {
+    // SAFETY: ffi
+    // SAFETY: raylib gives `count` nul-terminated paths, which are copied before
+    // being unloaded
+    unsafe {
+        let files = ffi::LoadDroppedFiles();
+        let paths = (0..files.count as usize)
+            .map(|i| *files.paths.add(i))
+            .filter(|path| !path.is_null())
+            .map(|path| PathBuf::from(CStr::from_ptr(path).to_string_lossy().into_owned()))
+            .collect();
+        ffi::UnloadDroppedFiles(files);
+        paths
+    }
+}
@@ -0,0 +1,192 @@
+use std::ffi::{CStr, CString};
+
+use crate::core::linalg::Vector2;
+use crate::error::{self, DiotekoErr};
+use crate::ffi;
+
+/// Maximum number of gamepads raylib tracks (`MAX_GAMEPADS` in raylib's config.h)
+pub const MAX_GAMEPADS: i32 = 4;
+
+/// A handle of a gamepad slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gamepad(pub i32);
+
+/// Iterate over every connected gamepad
+pub fn gamepads() -> impl Iterator<Item = Gamepad> {
+    (0..MAX_GAMEPADS)
+        .map(Gamepad)
+        .filter(|gamepad| gamepad.is_available())
+}
+
+/// Update the gamepad mappings with SDL_GameControllerDB formatted text
+pub fn set_mappings(mappings: &str) -> error::Result<()> {
+    let mappings = CString::new(mappings)?;
+
+    // SAFETY: ffi
+    if unsafe { ffi::SetGamepadMappings(mappings.as_ptr()) } == 0 {
+        return Err(DiotekoErr::InvalidGamepadMappingsErr);
+    }
+
+    Ok(())
+}
+
+/// Load a SDL_GameControllerDB file (e.g. `gamecontrollerdb.txt`) and update the
+/// gamepad mappings with it
+pub fn load_mappings(filename: &CStr) -> error::Result<()> {
+    // SAFETY: ffi
+    let text = unsafe { ffi::LoadFileText(filename.as_ptr()) };
+    if text.is_null() {
+        return Err(DiotekoErr::load_file(
+            error::path_of(filename),
+            "could not read the file",
+        ));
+    }
+
+    // SAFETY: ffi
+    // SAFETY: text is a nul-terminated string allocated by LoadFileText, and it is
+    // unloaded only once right after being used
+    let updated = unsafe {
+        let updated = ffi::SetGamepadMappings(text);
+        ffi::UnloadFileText(text);
+        updated
+    };
+
+    if updated == 0 {
+        return Err(DiotekoErr::InvalidGamepadMappingsErr);
+    }
+
+    Ok(())
+}
+
+impl Gamepad {
+    pub fn is_available(self) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsGamepadAvailable(self.0)) }
+    }
+
+    /// Get the internal name of the gamepad. Returns `None` if it is not available
+    pub fn name(self) -> Option<String> {
+        // SAFETY: ffi
+        let name = unsafe { ffi::GetGamepadName(self.0) };
+        if name.is_null() {
+            return None;
+        }
+
+        // SAFETY: raylib returns a nul-terminated string owned by GLFW
+        Some(
+            unsafe { CStr::from_ptr(name) }
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+
+    pub fn is_pressed(self, button: GamepadButton) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsGamepadButtonPressed(self.0, button.into())) }
+    }
+
+    pub fn is_down(self, button: GamepadButton) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsGamepadButtonDown(self.0, button.into())) }
+    }
+
+    pub fn is_released(self, button: GamepadButton) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsGamepadButtonReleased(self.0, button.into())) }
+    }
+
+    pub fn is_up(self, button: GamepadButton) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsGamepadButtonUp(self.0, button.into())) }
+    }
+
+    pub fn axis_count(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGamepadAxisCount(self.0) }
+    }
+
+    /// Get the raw movement of the axis in the range [-1.0, 1.0]
+    pub fn axis(self, axis: GamepadAxis) -> f32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGamepadAxisMovement(self.0, axis.into()) }
+    }
+
+    /// Get the movement of the axis where every movement smaller than `dead_zone` is
+    /// ignored. The rest is rescaled so that the output still covers [-1.0, 1.0].
+    pub fn axis_with_dead_zone(self, axis: GamepadAxis, dead_zone: f32) -> f32 {
+        apply_dead_zone(self.axis(axis), dead_zone)
+    }
+
+    /// Get the left stick with a radial dead zone
+    pub fn left_stick(self, dead_zone: f32) -> Vector2 {
+        self.stick(GamepadAxis::LeftX, GamepadAxis::LeftY, dead_zone)
+    }
+
+    /// Get the right stick with a radial dead zone
+    pub fn right_stick(self, dead_zone: f32) -> Vector2 {
+        self.stick(GamepadAxis::RightX, GamepadAxis::RightY, dead_zone)
+    }
+
+    fn stick(self, x_axis: GamepadAxis, y_axis: GamepadAxis, dead_zone: f32) -> Vector2 {
+        let (x, y) = (self.axis(x_axis), self.axis(y_axis));
+        let length = (x * x + y * y).sqrt();
+        if length <= dead_zone {
+            return Vector2::new(0.0, 0.0);
+        }
+
+        let scale = apply_dead_zone(length.min(1.0), dead_zone) / length;
+        Vector2::new(x * scale, y * scale)
+    }
+
+    /// Get the last pressed button among all gamepads.
+    /// Returns `None` if no button is pressed.
+    pub fn get_button_pressed() -> Option<GamepadButton> {
+        // SAFETY: ffi
+        match GamepadButton::try_from(unsafe { ffi::GetGamepadButtonPressed() }) {
+            Ok(GamepadButton::Unknown) | Err(_) => None,
+            Ok(button) => Some(button),
+        }
+    }
+}
+
+fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    let dead_zone = dead_zone.clamp(0.0, 0.99);
+    if value.abs() <= dead_zone {
+        0.0
+    } else {
+        value.signum() * (value.abs() - dead_zone) / (1.0 - dead_zone)
+    }
+}
+
+impl_raylib_enum![
+    GamepadButton =>
+    (Unknown       , GamepadButton_GAMEPAD_BUTTON_UNKNOWN),
+    (LeftFaceUp    , GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_UP),
+    (LeftFaceRight , GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_RIGHT),
+    (LeftFaceDown  , GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_DOWN),
+    (LeftFaceLeft  , GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_LEFT),
+    (RightFaceUp   , GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_UP),
+    (RightFaceRight, GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_RIGHT),
+    (RightFaceDown , GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_DOWN),
+    (RightFaceLeft , GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_LEFT),
+    (LeftTrigger1  , GamepadButton_GAMEPAD_BUTTON_LEFT_TRIGGER_1),
+    (LeftTrigger2  , GamepadButton_GAMEPAD_BUTTON_LEFT_TRIGGER_2),
+    (RightTrigger1 , GamepadButton_GAMEPAD_BUTTON_RIGHT_TRIGGER_1),
+    (RightTrigger2 , GamepadButton_GAMEPAD_BUTTON_RIGHT_TRIGGER_2),
+    (MiddleLeft    , GamepadButton_GAMEPAD_BUTTON_MIDDLE_LEFT),
+    (Middle        , GamepadButton_GAMEPAD_BUTTON_MIDDLE),
+    (MiddleRight   , GamepadButton_GAMEPAD_BUTTON_MIDDLE_RIGHT),
+    (LeftThumb     , GamepadButton_GAMEPAD_BUTTON_LEFT_THUMB),
+    (RightThumb    , GamepadButton_GAMEPAD_BUTTON_RIGHT_THUMB),
+];
+
+impl_raylib_enum![
+    GamepadAxis =>
+    (LeftX       , GamepadAxis_GAMEPAD_AXIS_LEFT_X),
+    (LeftY       , GamepadAxis_GAMEPAD_AXIS_LEFT_Y),
+    (RightX      , GamepadAxis_GAMEPAD_AXIS_RIGHT_X),
+    (RightY      , GamepadAxis_GAMEPAD_AXIS_RIGHT_Y),
+    (LeftTrigger , GamepadAxis_GAMEPAD_AXIS_LEFT_TRIGGER),
+    (RightTrigger, GamepadAxis_GAMEPAD_AXIS_RIGHT_TRIGGER),
+];
@@ -0,0 +1,271 @@
+//! Input State Module
+//!
+//! The functions of `keyboard` and `mouse` ask raylib directly, so code using them
+//! needs a window. `InputState` is a plain snapshot of the keyboard and the mouse in
+//! one frame instead. Capture it once per frame with `InputState::capture`, and write
+//! game logic against `&InputState`. Tests can build the state by hand without any
+//! window:
+//!
+//! ```
+//! use dioteko::prelude::*;
+//!
+//! let input = InputState::new()
+//!     .with_key_down(Key::LeftShift)
+//!     .with_key_pressed(Key::Space);
+//! assert!(input.is_key_pressed(Key::Space));
+//! assert!(input.is_key_down(Key::LeftShift));
+//! assert!(!input.is_key_released(Key::Space));
+//! ```
+
+use std::collections::HashSet;
+
+use crate::core::keyboard::Key;
+use crate::core::linalg::Vector2;
+use crate::core::mouse::{self, MouseButton};
+use crate::ffi;
+
+/// A snapshot of the keyboard and the mouse in one frame
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputState {
+    keys_down: HashSet<Key>,
+    keys_pressed: HashSet<Key>,
+    keys_repeated: HashSet<Key>,
+    keys_released: HashSet<Key>,
+    buttons_down: HashSet<MouseButton>,
+    buttons_pressed: HashSet<MouseButton>,
+    buttons_released: HashSet<MouseButton>,
+    mouse_position: Vector2,
+    mouse_delta: Vector2,
+    mouse_wheel: Vector2,
+    chars: Vec<char>,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputState {
+    /// Make a state where nothing is pressed and the mouse is at the origin
+    pub fn new() -> Self {
+        Self {
+            keys_down: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            keys_repeated: HashSet::new(),
+            keys_released: HashSet::new(),
+            buttons_down: HashSet::new(),
+            buttons_pressed: HashSet::new(),
+            buttons_released: HashSet::new(),
+            mouse_position: Vector2::new(0.0, 0.0),
+            mouse_delta: Vector2::new(0.0, 0.0),
+            mouse_wheel: Vector2::new(0.0, 0.0),
+            chars: Vec::new(),
+        }
+    }
+
+    /// Capture the input of the current frame from raylib.
+    ///
+    /// This drains the queue of typed characters, so `Window::events` and
+    /// `Key::get_char_pressed` see no characters afterwards in the same frame.
+    pub fn capture() -> Self {
+        let mut state = Self::new();
+
+        for &key in Key::ALL {
+            if key.is_down() {
+                state.keys_down.insert(key);
+            }
+            if key.is_pressed() {
+                state.keys_pressed.insert(key);
+            }
+            if key.is_pressed_repeat() {
+                state.keys_repeated.insert(key);
+            }
+            if key.is_released() {
+                state.keys_released.insert(key);
+            }
+        }
+
+        for &button in MouseButton::ALL {
+            if button.is_down() {
+                state.buttons_down.insert(button);
+            }
+            if button.is_pressed() {
+                state.buttons_pressed.insert(button);
+            }
+            if button.is_released() {
+                state.buttons_released.insert(button);
+            }
+        }
+
+        state.mouse_position = mouse::get_mouse_position();
+        state.mouse_delta = mouse::get_mouse_delta();
+        state.mouse_wheel = mouse::get_mouse_wheel_move_v();
+
+        loop {
+            // SAFETY: ffi
+            let ch = unsafe { ffi::GetCharPressed() };
+            if ch == 0 {
+                break;
+            }
+            if let Some(ch) = char::from_u32(ch as u32) {
+                state.chars.push(ch);
+            }
+        }
+
+        state
+    }
+
+    /// The key is held in this frame
+    #[inline]
+    pub fn with_key_down(mut self, key: Key) -> Self {
+        self.keys_down.insert(key);
+        self
+    }
+
+    /// The key is pressed in this frame, so it is held as well
+    #[inline]
+    pub fn with_key_pressed(mut self, key: Key) -> Self {
+        self.keys_pressed.insert(key);
+        self.keys_down.insert(key);
+        self
+    }
+
+    /// The key is held and repeated by the OS in this frame
+    #[inline]
+    pub fn with_key_repeated(mut self, key: Key) -> Self {
+        self.keys_repeated.insert(key);
+        self.keys_down.insert(key);
+        self
+    }
+
+    /// The key is released in this frame, so it is not held anymore
+    #[inline]
+    pub fn with_key_released(mut self, key: Key) -> Self {
+        self.keys_released.insert(key);
+        self.keys_down.remove(&key);
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_button_down(mut self, button: MouseButton) -> Self {
+        self.buttons_down.insert(button);
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_button_pressed(mut self, button: MouseButton) -> Self {
+        self.buttons_pressed.insert(button);
+        self.buttons_down.insert(button);
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_button_released(mut self, button: MouseButton) -> Self {
+        self.buttons_released.insert(button);
+        self.buttons_down.remove(&button);
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_position(mut self, position: Vector2) -> Self {
+        self.mouse_position = position;
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_delta(mut self, delta: Vector2) -> Self {
+        self.mouse_delta = delta;
+        self
+    }
+
+    #[inline]
+    pub fn with_mouse_wheel(mut self, wheel: Vector2) -> Self {
+        self.mouse_wheel = wheel;
+        self
+    }
+
+    /// Append the characters of `text` to the typed characters
+    #[inline]
+    pub fn with_chars(mut self, text: &str) -> Self {
+        self.chars.extend(text.chars());
+        self
+    }
+
+    #[inline]
+    pub fn is_key_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    #[inline]
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    #[inline]
+    pub fn is_key_pressed_repeat(&self, key: Key) -> bool {
+        self.keys_repeated.contains(&key)
+    }
+
+    #[inline]
+    pub fn is_key_released(&self, key: Key) -> bool {
+        self.keys_released.contains(&key)
+    }
+
+    #[inline]
+    pub fn is_key_up(&self, key: Key) -> bool {
+        !self.is_key_down(key)
+    }
+
+    #[inline]
+    pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.buttons_down.contains(&button)
+    }
+
+    #[inline]
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.buttons_pressed.contains(&button)
+    }
+
+    #[inline]
+    pub fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        self.buttons_released.contains(&button)
+    }
+
+    #[inline]
+    pub fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+        !self.is_mouse_button_down(button)
+    }
+
+    /// Iterate over the held keys in no particular order
+    pub fn keys_down(&self) -> impl Iterator<Item = Key> + '_ {
+        self.keys_down.iter().copied()
+    }
+
+    /// Iterate over the keys pressed in this frame in no particular order
+    pub fn keys_pressed(&self) -> impl Iterator<Item = Key> + '_ {
+        self.keys_pressed.iter().copied()
+    }
+
+    #[inline]
+    pub fn mouse_position(&self) -> Vector2 {
+        self.mouse_position
+    }
+
+    #[inline]
+    pub fn mouse_delta(&self) -> Vector2 {
+        self.mouse_delta
+    }
+
+    #[inline]
+    pub fn mouse_wheel(&self) -> Vector2 {
+        self.mouse_wheel
+    }
+
+    /// The characters typed in this frame in the typing order
+    #[inline]
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+}
@@ -0,0 +1,95 @@
+use ::glam::{Mat4, Quat, Vec2, Vec3, Vec4};
+
+use crate::core::linalg::{Matrix, Quaternion, Vector2, Vector3, Vector4};
+use crate::core::rectangle::Rectangle;
+
+impl From<Vec2> for Vector2 {
+    #[inline]
+    fn from(vec: Vec2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<Vector2> for Vec2 {
+    #[inline]
+    fn from(vec: Vector2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<Vec3> for Vector3 {
+    #[inline]
+    fn from(vec: Vec3) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Vector3> for Vec3 {
+    #[inline]
+    fn from(vec: Vector3) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Vec4> for Vector4 {
+    #[inline]
+    fn from(vec: Vec4) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+impl From<Vector4> for Vec4 {
+    #[inline]
+    fn from(vec: Vector4) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+impl From<Quat> for Quaternion {
+    #[inline]
+    fn from(qua: Quat) -> Self {
+        Self::new(qua.x, qua.y, qua.z, qua.w)
+    }
+}
+
+impl From<Quaternion> for Quat {
+    #[inline]
+    fn from(qua: Quaternion) -> Self {
+        Self::from_xyzw(qua.x, qua.y, qua.z, qua.w)
+    }
+}
+
+// Both are column-major
+impl From<Mat4> for Matrix {
+    #[inline]
+    fn from(mat: Mat4) -> Self {
+        Self::from_array(mat.to_cols_array())
+    }
+}
+
+impl From<Matrix> for Mat4 {
+    #[inline]
+    fn from(mat: Matrix) -> Self {
+        Self::from_cols_array(&mat.to_array())
+    }
+}
+
+// A rectangle is the pair `(position, size)`
+impl From<(Vec2, Vec2)> for Rectangle {
+    #[inline]
+    fn from((position, size): (Vec2, Vec2)) -> Self {
+        Self {
+            x: position.x,
+            y: position.y,
+            width: size.x,
+            height: size.y,
+        }
+    }
+}
+
+impl From<Rectangle> for (Vec2, Vec2) {
+    #[inline]
+    fn from(rec: Rectangle) -> Self {
+        (Vec2::new(rec.x, rec.y), Vec2::new(rec.width, rec.height))
+    }
+}
@@ -0,0 +1,156 @@
+use ::mint::{ColumnMatrix4, Point2, Point3, RowMatrix4, Vector2, Vector3, Vector4};
+
+use crate::core::linalg::{self, Matrix, Quaternion};
+use crate::core::rectangle::Rectangle;
+
+impl From<Vector2<f32>> for linalg::Vector2 {
+    #[inline]
+    fn from(vec: Vector2<f32>) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<linalg::Vector2> for Vector2<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector2) -> Self {
+        Self { x: vec.x, y: vec.y }
+    }
+}
+
+impl From<Point2<f32>> for linalg::Vector2 {
+    #[inline]
+    fn from(point: Point2<f32>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl From<linalg::Vector2> for Point2<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector2) -> Self {
+        Self { x: vec.x, y: vec.y }
+    }
+}
+
+impl From<Vector3<f32>> for linalg::Vector3 {
+    #[inline]
+    fn from(vec: Vector3<f32>) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<linalg::Vector3> for Vector3<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector3) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
+    }
+}
+
+impl From<Point3<f32>> for linalg::Vector3 {
+    #[inline]
+    fn from(point: Point3<f32>) -> Self {
+        Self::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<linalg::Vector3> for Point3<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector3) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+        }
+    }
+}
+
+impl From<Vector4<f32>> for linalg::Vector4 {
+    #[inline]
+    fn from(vec: Vector4<f32>) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+impl From<linalg::Vector4> for Vector4<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector4) -> Self {
+        Self {
+            x: vec.x,
+            y: vec.y,
+            z: vec.z,
+            w: vec.w,
+        }
+    }
+}
+
+impl From<::mint::Quaternion<f32>> for Quaternion {
+    #[inline]
+    fn from(qua: ::mint::Quaternion<f32>) -> Self {
+        Self::new(qua.v.x, qua.v.y, qua.v.z, qua.s)
+    }
+}
+
+impl From<Quaternion> for ::mint::Quaternion<f32> {
+    #[inline]
+    fn from(qua: Quaternion) -> Self {
+        [qua.x, qua.y, qua.z, qua.w].into()
+    }
+}
+
+// Both are column-major
+impl From<ColumnMatrix4<f32>> for Matrix {
+    #[inline]
+    fn from(mat: ColumnMatrix4<f32>) -> Self {
+        Self::from_array(mat.into())
+    }
+}
+
+impl From<Matrix> for ColumnMatrix4<f32> {
+    #[inline]
+    fn from(mat: Matrix) -> Self {
+        mat.to_array().into()
+    }
+}
+
+impl From<RowMatrix4<f32>> for Matrix {
+    #[inline]
+    fn from(mat: RowMatrix4<f32>) -> Self {
+        ColumnMatrix4::from(mat).into()
+    }
+}
+
+impl From<Matrix> for RowMatrix4<f32> {
+    #[inline]
+    fn from(mat: Matrix) -> Self {
+        ColumnMatrix4::from(mat).into()
+    }
+}
+
+// A rectangle is the pair `(position, size)`
+impl From<(Point2<f32>, Vector2<f32>)> for Rectangle {
+    #[inline]
+    fn from((position, size): (Point2<f32>, Vector2<f32>)) -> Self {
+        Self {
+            x: position.x,
+            y: position.y,
+            width: size.x,
+            height: size.y,
+        }
+    }
+}
+
+impl From<Rectangle> for (Point2<f32>, Vector2<f32>) {
+    #[inline]
+    fn from(rec: Rectangle) -> Self {
+        (
+            Point2 { x: rec.x, y: rec.y },
+            Vector2 {
+                x: rec.width,
+                y: rec.height,
+            },
+        )
+    }
+}
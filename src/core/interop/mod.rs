@@ -0,0 +1,20 @@
+//! Interop Module
+//!
+//! `From`/`Into` conversions between the math types of dioteko and the ones of
+//! other math crates, each behind the cargo feature of the same name:
+//!
+//! - `glam`: `Vec2`, `Vec3`, `Vec4`, `Quat` and `Mat4`
+//! - `mint`: `Vector2`, `Point2`, `Vector3`, `Point3`, `Vector4`, `Quaternion`,
+//!   `ColumnMatrix4` and `RowMatrix4`
+//! - `nalgebra`: `Vector2`, `Point2`, `Vector3`, `Point3`, `Vector4`, `Quaternion`,
+//!   `UnitQuaternion` and `Matrix4`
+//!
+//! None of these crates has a rectangle, so a `Rectangle` converts to and from a
+//! `(position, size)` pair of their 2D vectors (points for `mint` and `nalgebra`).
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,144 @@
+use ::nalgebra::{Matrix4, Point2, Point3, UnitQuaternion, Vector2, Vector3, Vector4};
+
+use crate::core::linalg::{self, Matrix, Quaternion};
+use crate::core::rectangle::Rectangle;
+
+impl From<Vector2<f32>> for linalg::Vector2 {
+    #[inline]
+    fn from(vec: Vector2<f32>) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<linalg::Vector2> for Vector2<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<Point2<f32>> for linalg::Vector2 {
+    #[inline]
+    fn from(point: Point2<f32>) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl From<linalg::Vector2> for Point2<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector2) -> Self {
+        Self::new(vec.x, vec.y)
+    }
+}
+
+impl From<Vector3<f32>> for linalg::Vector3 {
+    #[inline]
+    fn from(vec: Vector3<f32>) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<linalg::Vector3> for Vector3<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector3) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Point3<f32>> for linalg::Vector3 {
+    #[inline]
+    fn from(point: Point3<f32>) -> Self {
+        Self::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<linalg::Vector3> for Point3<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector3) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Vector4<f32>> for linalg::Vector4 {
+    #[inline]
+    fn from(vec: Vector4<f32>) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+impl From<linalg::Vector4> for Vector4<f32> {
+    #[inline]
+    fn from(vec: linalg::Vector4) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+// nalgebra takes the scalar part first in `Quaternion::new`
+impl From<::nalgebra::Quaternion<f32>> for Quaternion {
+    #[inline]
+    fn from(qua: ::nalgebra::Quaternion<f32>) -> Self {
+        Self::new(qua.i, qua.j, qua.k, qua.w)
+    }
+}
+
+impl From<Quaternion> for ::nalgebra::Quaternion<f32> {
+    #[inline]
+    fn from(qua: Quaternion) -> Self {
+        Self::new(qua.w, qua.x, qua.y, qua.z)
+    }
+}
+
+impl From<UnitQuaternion<f32>> for Quaternion {
+    #[inline]
+    fn from(qua: UnitQuaternion<f32>) -> Self {
+        qua.into_inner().into()
+    }
+}
+
+// The quaternion is normalized, since a `UnitQuaternion` must have the length 1
+impl From<Quaternion> for UnitQuaternion<f32> {
+    #[inline]
+    fn from(qua: Quaternion) -> Self {
+        Self::new_normalize(qua.into())
+    }
+}
+
+// Both are column-major
+impl From<Matrix4<f32>> for Matrix {
+    #[inline]
+    fn from(mat: Matrix4<f32>) -> Self {
+        let mut array = [0.0; 16];
+        array.copy_from_slice(mat.as_slice());
+        Self::from_array(array)
+    }
+}
+
+impl From<Matrix> for Matrix4<f32> {
+    #[inline]
+    fn from(mat: Matrix) -> Self {
+        Self::from_column_slice(&mat.to_array())
+    }
+}
+
+// A rectangle is the pair `(position, size)`
+impl From<(Point2<f32>, Vector2<f32>)> for Rectangle {
+    #[inline]
+    fn from((position, size): (Point2<f32>, Vector2<f32>)) -> Self {
+        Self {
+            x: position.x,
+            y: position.y,
+            width: size.x,
+            height: size.y,
+        }
+    }
+}
+
+impl From<Rectangle> for (Point2<f32>, Vector2<f32>) {
+    #[inline]
+    fn from(rec: Rectangle) -> Self {
+        (
+            Point2::new(rec.x, rec.y),
+            Vector2::new(rec.width, rec.height),
+        )
+    }
+}
@@ -10,6 +10,12 @@ impl Key {
         unsafe { to_bool!(ffi::IsKeyPressed(self.into())) }
     }
 
+    /// Check whether the key is pressed again by the key repeat of the OS
+    pub fn is_pressed_repeat(self) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsKeyPressedRepeat(self.into())) }
+    }
+
     pub fn is_down(self) -> bool {
         // SAFETY: ffi
         unsafe { to_bool!(ffi::IsKeyDown(self.into())) }
@@ -25,9 +31,20 @@ impl Key {
         unsafe { to_bool!(ffi::IsKeyUp(self.into())) }
     }
 
-    pub fn get_key_pressed() -> Self {
-        // SAFETY: ffi
-        unsafe { ffi::GetKeyPressed().into() }
+    /// Get the next key in the pressed key queue.
+    /// Returns `None` if the queue is empty. Keys unknown to `Key` are skipped.
+    pub fn get_key_pressed() -> Option<Self> {
+        loop {
+            // SAFETY: ffi
+            match unsafe { ffi::GetKeyPressed() } {
+                0 => return None,
+                key => {
+                    if let Ok(key) = Self::try_from(key) {
+                        return Some(key);
+                    }
+                }
+            }
+        }
     }
 
     pub fn get_char_pressed() -> Option<char> {
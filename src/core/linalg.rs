@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 use crate::ffi;
 
@@ -7,68 +10,393 @@ impl_vec!(Vector2 | x, y);
 impl_vec!(Vector3 | x, y, z);
 impl_vec!(Vector4 | x, y, z, w);
 
-impl From<(f32, f32)> for Vector2 {
-    fn from(tup: (f32, f32)) -> Self {
-        Self { x: tup.0, y: tup.1 }
+/// The tolerance of `approx_eq`, same as raymath's `EPSILON`
+pub const EPSILON: f32 = 0.000001;
+
+impl Vector2 {
+    /// The signed angle in radians from `self` to `rhs` (`Vector2Angle`)
+    pub fn angle(self, rhs: Self) -> f32 {
+        let dot = self.dot(rhs);
+        let det = self.x * rhs.y - self.y * rhs.x;
+        det.atan2(dot)
     }
-}
 
-impl From<(f32, f32, f32)> for Vector3 {
-    fn from(tup: (f32, f32, f32)) -> Self {
+    /// Rotate the vector `angle` radians counterclockwise
+    pub fn rotate(self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
         Self {
-            x: tup.0,
-            y: tup.1,
-            z: tup.2,
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
         }
     }
 }
 
-impl From<(f32, f32, f32, f32)> for Vector4 {
-    fn from(tup: (f32, f32, f32, f32)) -> Self {
+impl Vector3 {
+    pub fn cross(self, rhs: Self) -> Self {
         Self {
-            x: tup.0,
-            y: tup.1,
-            z: tup.2,
-            w: tup.3,
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    /// The unsigned angle in radians between `self` and `rhs` (`Vector3Angle`)
+    pub fn angle(self, rhs: Self) -> f32 {
+        self.cross(rhs).length().atan2(self.dot(rhs))
+    }
+
+    /// Refract the vector on the surface with the given normal, where `ratio` is the
+    /// ratio of the refractive indices. Both vectors should be normalized.
+    /// The total internal reflection gives the zero vector.
+    pub fn refract(self, normal: Self, ratio: f32) -> Self {
+        let dot = self.dot(normal);
+        let d = 1.0 - ratio * ratio * (1.0 - dot * dot);
+        if d < 0.0 {
+            return Self::ZERO;
         }
+
+        self * ratio - normal * (ratio * dot + d.sqrt())
+    }
+
+    /// The barycentric coordinates of the point `self` in the triangle `(a, b, c)`
+    /// (`Vector3Barycenter`)
+    pub fn barycentric(self, a: Self, b: Self, c: Self) -> Self {
+        let (v0, v1, v2) = (b - a, c - a, self - a);
+        let d00 = v0.dot(v0);
+        let d01 = v0.dot(v1);
+        let d11 = v1.dot(v1);
+        let d20 = v2.dot(v0);
+        let d21 = v2.dot(v1);
+        let denom = d00 * d11 - d01 * d01;
+
+        let y = (d11 * d20 - d01 * d21) / denom;
+        let z = (d00 * d21 - d01 * d20) / denom;
+        Self::new(1.0 - (y + z), y, z)
     }
 }
 
 // Quaternion Implementations
-pub struct Quaternion(pub f32, pub f32, pub f32, pub f32);
+
+/// A rotation quaternion with the same layout as raymath's one, where `w` is the
+/// scalar part
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    /// A rotation of `angle` radians around `axis`. The axis does not need to be
+    /// normalized, and the zero axis gives the identity.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        if axis.length() == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        let axis = axis.normalize();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self::new(axis.x * sin, axis.y * sin, axis.z * sin, cos).normalize()
+    }
+
+    /// Get the axis and the angle in radians of the rotation
+    pub fn to_axis_angle(self) -> (Vector3, f32) {
+        let q = if self.w.abs() > 1.0 {
+            self.normalize()
+        } else {
+            self
+        };
+
+        let angle = 2.0 * q.w.acos();
+        let den = (1.0 - q.w * q.w).sqrt();
+        let axis = if den > EPSILON {
+            Vector3::new(q.x / den, q.y / den, q.z / den)
+        } else {
+            // Any axis works for the zero rotation
+            Vector3::new(1.0, 0.0, 0.0)
+        };
+
+        (axis, angle)
+    }
+
+    /// A rotation from the angles in radians around the X (`pitch`), Y (`yaw`) and
+    /// Z (`roll`) axes (`QuaternionFromEuler`)
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Self {
+        let (x1, x0) = (pitch * 0.5).sin_cos();
+        let (y1, y0) = (yaw * 0.5).sin_cos();
+        let (z1, z0) = (roll * 0.5).sin_cos();
+
+        Self {
+            x: x1 * y0 * z0 - x0 * y1 * z1,
+            y: x0 * y1 * z0 + x1 * y0 * z1,
+            z: x0 * y0 * z1 - x1 * y1 * z0,
+            w: x0 * y0 * z0 + x1 * y1 * z1,
+        }
+    }
+
+    /// Get the angles in radians around the X, Y and Z axes, which are the inverse
+    /// of `from_euler` (`QuaternionToEuler`)
+    pub fn to_euler(self) -> Vector3 {
+        let Self { x, y, z, w } = self;
+
+        let x0 = 2.0 * (w * x + y * z);
+        let x1 = 1.0 - 2.0 * (x * x + y * y);
+        let y0 = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0);
+        let z0 = 2.0 * (w * z + x * y);
+        let z1 = 1.0 - 2.0 * (y * y + z * z);
+
+        Vector3::new(x0.atan2(x1), y0.asin(), z0.atan2(z1))
+    }
+
+    /// Get the rotation of a rotation matrix (`QuaternionFromMatrix`)
+    pub fn from_matrix(mat: Matrix) -> Self {
+        let four_w_squared_minus_1 = mat.m0 + mat.m5 + mat.m10;
+        let four_x_squared_minus_1 = mat.m0 - mat.m5 - mat.m10;
+        let four_y_squared_minus_1 = mat.m5 - mat.m0 - mat.m10;
+        let four_z_squared_minus_1 = mat.m10 - mat.m0 - mat.m5;
+
+        // Divides by the biggest component for the numerical stability
+        let (biggest_index, four_biggest_squared_minus_1) = [
+            four_w_squared_minus_1,
+            four_x_squared_minus_1,
+            four_y_squared_minus_1,
+            four_z_squared_minus_1,
+        ]
+        .into_iter()
+        .enumerate()
+        .fold((0, f32::NEG_INFINITY), |biggest, (i, value)| {
+            if value > biggest.1 {
+                (i, value)
+            } else {
+                biggest
+            }
+        });
+
+        let biggest = (four_biggest_squared_minus_1 + 1.0).sqrt() * 0.5;
+        let mult = 0.25 / biggest;
+
+        match biggest_index {
+            0 => Self {
+                x: (mat.m6 - mat.m9) * mult,
+                y: (mat.m8 - mat.m2) * mult,
+                z: (mat.m1 - mat.m4) * mult,
+                w: biggest,
+            },
+            1 => Self {
+                x: biggest,
+                y: (mat.m1 + mat.m4) * mult,
+                z: (mat.m8 + mat.m2) * mult,
+                w: (mat.m6 - mat.m9) * mult,
+            },
+            2 => Self {
+                x: (mat.m1 + mat.m4) * mult,
+                y: biggest,
+                z: (mat.m6 + mat.m9) * mult,
+                w: (mat.m8 - mat.m2) * mult,
+            },
+            _ => Self {
+                x: (mat.m8 + mat.m2) * mult,
+                y: (mat.m6 + mat.m9) * mult,
+                z: biggest,
+                w: (mat.m1 - mat.m4) * mult,
+            },
+        }
+    }
+
+    /// Get the rotation matrix of the quaternion (`QuaternionToMatrix`)
+    pub fn to_matrix(self) -> Matrix {
+        let Self { x, y, z, w } = self;
+        let (a2, b2, c2) = (x * x, y * y, z * z);
+        let (ac, ab, bc) = (x * z, x * y, y * z);
+        let (ad, bd, cd) = (w * x, w * y, w * z);
+
+        Matrix {
+            m0: 1.0 - 2.0 * (b2 + c2),
+            m1: 2.0 * (ab + cd),
+            m2: 2.0 * (ac - bd),
+            m4: 2.0 * (ab - cd),
+            m5: 1.0 - 2.0 * (a2 + c2),
+            m6: 2.0 * (bc + ad),
+            m8: 2.0 * (ac + bd),
+            m9: 2.0 * (bc - ad),
+            m10: 1.0 - 2.0 * (a2 + b2),
+            ..Matrix::IDENTITY
+        }
+    }
+
+    /// The shortest rotation which turns the direction of `from` into the one of `to`
+    pub fn from_vector3_to_vector3(from: Vector3, to: Vector3) -> Self {
+        let cos_2_theta = from.dot(to);
+        let cross = from.cross(to);
+        Self::new(cross.x, cross.y, cross.z, 1.0 + cos_2_theta).normalize()
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Scale the quaternion to the length 1. The zero quaternion stays zero.
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            self
+        } else {
+            (1.0 / length) * self
+        }
+    }
+
+    /// The conjugate, which is the inverse of a normalized quaternion
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// The inverse of the quaternion. The zero quaternion stays zero.
+    pub fn invert(self) -> Self {
+        let length_sqr = self.dot(self);
+        if length_sqr == 0.0 {
+            self
+        } else {
+            (1.0 / length_sqr) * self.conjugate()
+        }
+    }
+
+    /// Linear interpolation of every component
+    pub fn lerp(self, rhs: Self, amount: f32) -> Self {
+        self + amount * (rhs - self)
+    }
+
+    /// Normalized linear interpolation, which is cheaper than `slerp` but does not
+    /// rotate at a constant speed
+    #[inline]
+    pub fn nlerp(self, rhs: Self, amount: f32) -> Self {
+        self.lerp(rhs, amount).normalize()
+    }
+
+    /// Spherical linear interpolation, which rotates along the shortest arc at a
+    /// constant speed
+    pub fn slerp(self, rhs: Self, amount: f32) -> Self {
+        let mut rhs = rhs;
+        let mut cos_half_theta = self.dot(rhs);
+
+        // q and -q are the same rotation, so takes the shorter one
+        if cos_half_theta < 0.0 {
+            rhs = -rhs;
+            cos_half_theta = -cos_half_theta;
+        }
+
+        if cos_half_theta.abs() >= 1.0 {
+            return self;
+        }
+        if cos_half_theta > 0.95 {
+            return self.nlerp(rhs, amount);
+        }
+
+        let half_theta = cos_half_theta.acos();
+        let sin_half_theta = (1.0 - cos_half_theta * cos_half_theta).sqrt();
+        if sin_half_theta.abs() < EPSILON {
+            return 0.5 * (self + rhs);
+        }
+
+        let ratio_a = ((1.0 - amount) * half_theta).sin() / sin_half_theta;
+        let ratio_b = (amount * half_theta).sin() / sin_half_theta;
+        ratio_a * self + ratio_b * rhs
+    }
+
+    /// Rotate a vector (`Vector3RotateByQuaternion`)
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        let Self { x, y, z, w } = self;
+
+        Vector3 {
+            x: v.x * (x * x + w * w - y * y - z * z)
+                + v.y * (2.0 * x * y - 2.0 * w * z)
+                + v.z * (2.0 * x * z + 2.0 * w * y),
+            y: v.x * (2.0 * w * z + 2.0 * x * y)
+                + v.y * (w * w - x * x + y * y - z * z)
+                + v.z * (-2.0 * w * x + 2.0 * y * z),
+            z: v.x * (-2.0 * w * y + 2.0 * x * z)
+                + v.y * (2.0 * w * x + 2.0 * y * z)
+                + v.z * (w * w - x * x - y * y + z * z),
+        }
+    }
+
+    /// Check whether both are nearly the same rotation (`QuaternionEquals`).
+    /// `q` and `-q` are the same rotation.
+    pub fn approx_eq(self, rhs: Self) -> bool {
+        let lhs = Vector4::from(self);
+        lhs.approx_eq(Vector4::from(rhs)) || lhs.approx_eq(-Vector4::from(rhs))
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
 
 impl From<ffi::Quaternion> for Quaternion {
     fn from(qua: ffi::Quaternion) -> Self {
-        Self(qua.x, qua.y, qua.z, qua.w)
+        Self::new(qua.x, qua.y, qua.z, qua.w)
     }
 }
 
 impl From<Quaternion> for ffi::Quaternion {
     fn from(qua: Quaternion) -> ffi::Quaternion {
         ffi::Quaternion {
-            x: qua.0,
-            y: qua.1,
-            z: qua.2,
-            w: qua.3,
+            x: qua.x,
+            y: qua.y,
+            z: qua.z,
+            w: qua.w,
         }
     }
 }
 
+impl From<Vector4> for Quaternion {
+    fn from(vec: Vector4) -> Self {
+        Self::new(vec.x, vec.y, vec.z, vec.w)
+    }
+}
+
+impl From<Quaternion> for Vector4 {
+    fn from(qua: Quaternion) -> Self {
+        Self::new(qua.x, qua.y, qua.z, qua.w)
+    }
+}
+
 impl Neg for Quaternion {
     type Output = Self;
     fn neg(self) -> Self::Output {
-        Self(-self.0, -self.1, -self.2, -self.3)
+        Self::new(-self.x, -self.y, -self.z, -self.w)
     }
 }
 
 impl Add for Quaternion {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        Self(
-            self.0 + rhs.0,
-            self.1 + rhs.1,
-            self.2 + rhs.2,
-            self.3 + rhs.3,
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
         )
     }
 }
@@ -76,47 +404,537 @@ impl Add for Quaternion {
 impl Sub for Quaternion {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(
-            self.0 - rhs.0,
-            self.1 - rhs.1,
-            self.2 - rhs.2,
-            self.3 - rhs.3,
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
         )
     }
 }
 
+// Composition of rotations, where `rhs` is applied first (`QuaternionMultiply`)
 impl Mul for Quaternion {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        Self(
-            self.0 * rhs.0 - self.1 * rhs.1 - self.2 * rhs.2 - self.3 * rhs.3,
-            self.0 * rhs.1 + self.1 * rhs.0 + self.2 * rhs.3 - self.3 * rhs.2,
-            self.0 * rhs.2 - self.1 * rhs.3 + self.2 * rhs.0 + self.3 * rhs.1,
-            self.0 * rhs.3 + self.1 * rhs.2 - self.2 * rhs.1 + self.3 * rhs.0,
-        )
+        let Self { x, y, z, w } = self;
+        Self {
+            x: x * rhs.w + w * rhs.x + y * rhs.z - z * rhs.y,
+            y: y * rhs.w + w * rhs.y + z * rhs.x - x * rhs.z,
+            z: z * rhs.w + w * rhs.z + x * rhs.y - y * rhs.x,
+            w: w * rhs.w - x * rhs.x - y * rhs.y - z * rhs.z,
+        }
+    }
+}
+
+impl MulAssign for Quaternion {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
     }
 }
 
 impl Mul<Quaternion> for f32 {
     type Output = Quaternion;
     fn mul(self, rhs: Quaternion) -> Self::Output {
-        Quaternion(self * rhs.0, self * rhs.1, self * rhs.2, self * rhs.3)
+        Quaternion::new(self * rhs.x, self * rhs.y, self * rhs.z, self * rhs.w)
+    }
+}
+
+impl Mul<f32> for Quaternion {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.rotate(rhs)
     }
 }
 
 impl Div for Quaternion {
     type Output = Self;
     fn div(self, rhs: Self) -> Self::Output {
-        self * (1.0 / rhs)
+        // Multiplies by the inverse
+        Mul::mul(self, rhs.invert())
     }
 }
 
 impl Div<Quaternion> for f32 {
     type Output = Quaternion;
     fn div(self, rhs: Quaternion) -> Self::Output {
-        (self / (rhs.0 * rhs.0 + rhs.1 * rhs.1 + rhs.2 * rhs.2 + rhs.3 * rhs.3))
-            * Quaternion(rhs.0, -rhs.1, -rhs.2, -rhs.3)
+        Mul::mul(self, rhs.invert())
+    }
+}
+
+// Matrix Implementations
+
+/// A 4x4 matrix with the same layout as raymath's one.
+///
+/// The field `m{i}` is the `i`-th element in column-major order, so `m0..=m3` is the
+/// first column and `m12..=m14` is the translation. Vectors are columns, and they
+/// are transformed with `matrix * vector`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix {
+    pub m0: f32,
+    pub m4: f32,
+    pub m8: f32,
+    pub m12: f32,
+    pub m1: f32,
+    pub m5: f32,
+    pub m9: f32,
+    pub m13: f32,
+    pub m2: f32,
+    pub m6: f32,
+    pub m10: f32,
+    pub m14: f32,
+    pub m3: f32,
+    pub m7: f32,
+    pub m11: f32,
+    pub m15: f32,
+}
+
+impl Matrix {
+    pub const IDENTITY: Self = Self::from_array([
+        1.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0,
+    ]);
+
+    /// Make a matrix from its elements in column-major order
+    pub const fn from_array(m: [f32; 16]) -> Self {
+        Self {
+            m0: m[0],
+            m1: m[1],
+            m2: m[2],
+            m3: m[3],
+            m4: m[4],
+            m5: m[5],
+            m6: m[6],
+            m7: m[7],
+            m8: m[8],
+            m9: m[9],
+            m10: m[10],
+            m11: m[11],
+            m12: m[12],
+            m13: m[13],
+            m14: m[14],
+            m15: m[15],
+        }
+    }
+
+    /// Get the elements in column-major order (`MatrixToFloatV`)
+    pub const fn to_array(self) -> [f32; 16] {
+        [
+            self.m0, self.m1, self.m2, self.m3, //
+            self.m4, self.m5, self.m6, self.m7, //
+            self.m8, self.m9, self.m10, self.m11, //
+            self.m12, self.m13, self.m14, self.m15,
+        ]
+    }
+
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    pub fn translate(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            m12: x,
+            m13: y,
+            m14: z,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            m0: x,
+            m5: y,
+            m10: z,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotate `angle` radians around `axis`. The axis does not need to be normalized.
+    pub fn rotate(axis: Vector3, angle: f32) -> Self {
+        let Vector3 {
+            mut x,
+            mut y,
+            mut z,
+        } = axis;
+
+        let length_squared = x * x + y * y + z * z;
+        if length_squared != 1.0 && length_squared != 0.0 {
+            let inv_length = 1.0 / length_squared.sqrt();
+            x *= inv_length;
+            y *= inv_length;
+            z *= inv_length;
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        let t = 1.0 - cos;
+
+        Self {
+            m0: x * x * t + cos,
+            m1: y * x * t + z * sin,
+            m2: z * x * t - y * sin,
+            m4: x * y * t - z * sin,
+            m5: y * y * t + cos,
+            m6: z * y * t + x * sin,
+            m8: x * z * t + y * sin,
+            m9: y * z * t - x * sin,
+            m10: z * z * t + cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn rotate_x(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            m5: cos,
+            m6: sin,
+            m9: -sin,
+            m10: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn rotate_y(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            m0: cos,
+            m2: -sin,
+            m8: sin,
+            m10: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn rotate_z(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            m0: cos,
+            m1: sin,
+            m4: -sin,
+            m5: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Same as `rotate_x(angles.x) * rotate_y(angles.y) * rotate_z(angles.z)`
+    /// (`MatrixRotateXYZ`). The angles are in radians.
+    pub fn rotate_xyz(angles: Vector3) -> Self {
+        let (sin_z, cos_z) = (-angles.z).sin_cos();
+        let (sin_y, cos_y) = (-angles.y).sin_cos();
+        let (sin_x, cos_x) = (-angles.x).sin_cos();
+
+        Self {
+            m0: cos_z * cos_y,
+            m1: (cos_z * sin_y * sin_x) - (sin_z * cos_x),
+            m2: (cos_z * sin_y * cos_x) + (sin_z * sin_x),
+            m4: sin_z * cos_y,
+            m5: (sin_z * sin_y * sin_x) + (cos_z * cos_x),
+            m6: (sin_z * sin_y * cos_x) - (cos_z * sin_x),
+            m8: -sin_y,
+            m9: cos_y * sin_x,
+            m10: cos_y * cos_x,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Same as `rotate_z(angles.z) * rotate_y(angles.y) * rotate_x(angles.x)`
+    /// (`MatrixRotateZYX`). The angles are in radians.
+    pub fn rotate_zyx(angles: Vector3) -> Self {
+        let (sin_z, cos_z) = angles.z.sin_cos();
+        let (sin_y, cos_y) = angles.y.sin_cos();
+        let (sin_x, cos_x) = angles.x.sin_cos();
+
+        Self {
+            m0: cos_z * cos_y,
+            m4: cos_z * sin_y * sin_x - cos_x * sin_z,
+            m8: sin_z * sin_x + cos_z * cos_x * sin_y,
+            m1: cos_y * sin_z,
+            m5: cos_z * cos_x + sin_z * sin_y * sin_x,
+            m9: cos_x * sin_z * sin_y - cos_z * sin_x,
+            m2: -sin_y,
+            m6: cos_y * sin_x,
+            m10: cos_y * cos_x,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Same as raymath's `MatrixMultiply(self, rhs)`, which applies `self` first and
+    /// then `rhs`. This is `rhs * self`.
+    #[inline]
+    pub fn multiply(self, rhs: Self) -> Self {
+        rhs * self
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.to_array();
+        Self::from_array(std::array::from_fn(|i| m[(i % 4) * 4 + i / 4]))
+    }
+
+    pub fn determinant(self) -> f32 {
+        let [a00, a01, a02, a03, a10, a11, a12, a13, a20, a21, a22, a23, a30, a31, a32, a33] =
+            self.to_array();
+
+        a30 * a21 * a12 * a03 - a20 * a31 * a12 * a03 - a30 * a11 * a22 * a03
+            + a10 * a31 * a22 * a03
+            + a20 * a11 * a32 * a03
+            - a10 * a21 * a32 * a03
+            - a30 * a21 * a02 * a13
+            + a20 * a31 * a02 * a13
+            + a30 * a01 * a22 * a13
+            - a00 * a31 * a22 * a13
+            - a20 * a01 * a32 * a13
+            + a00 * a21 * a32 * a13
+            + a30 * a11 * a02 * a23
+            - a10 * a31 * a02 * a23
+            - a30 * a01 * a12 * a23
+            + a00 * a31 * a12 * a23
+            + a10 * a01 * a32 * a23
+            - a00 * a11 * a32 * a23
+            - a20 * a11 * a02 * a33
+            + a10 * a21 * a02 * a33
+            + a20 * a01 * a12 * a33
+            - a00 * a21 * a12 * a33
+            - a10 * a01 * a22 * a33
+            + a00 * a11 * a22 * a33
+    }
+
+    /// The sum of the diagonal elements
+    #[inline]
+    pub fn trace(self) -> f32 {
+        self.m0 + self.m5 + self.m10 + self.m15
+    }
+
+    /// Invert the matrix. A singular matrix gives non-finite elements like raymath.
+    pub fn invert(self) -> Self {
+        let [a00, a01, a02, a03, a10, a11, a12, a13, a20, a21, a22, a23, a30, a31, a32, a33] =
+            self.to_array();
+
+        let b00 = a00 * a11 - a01 * a10;
+        let b01 = a00 * a12 - a02 * a10;
+        let b02 = a00 * a13 - a03 * a10;
+        let b03 = a01 * a12 - a02 * a11;
+        let b04 = a01 * a13 - a03 * a11;
+        let b05 = a02 * a13 - a03 * a12;
+        let b06 = a20 * a31 - a21 * a30;
+        let b07 = a20 * a32 - a22 * a30;
+        let b08 = a20 * a33 - a23 * a30;
+        let b09 = a21 * a32 - a22 * a31;
+        let b10 = a21 * a33 - a23 * a31;
+        let b11 = a22 * a33 - a23 * a32;
+
+        let inv_det = 1.0 / (b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06);
+
+        Self::from_array([
+            (a11 * b11 - a12 * b10 + a13 * b09) * inv_det,
+            (-a01 * b11 + a02 * b10 - a03 * b09) * inv_det,
+            (a31 * b05 - a32 * b04 + a33 * b03) * inv_det,
+            (-a21 * b05 + a22 * b04 - a23 * b03) * inv_det,
+            (-a10 * b11 + a12 * b08 - a13 * b07) * inv_det,
+            (a00 * b11 - a02 * b08 + a03 * b07) * inv_det,
+            (-a30 * b05 + a32 * b02 - a33 * b01) * inv_det,
+            (a20 * b05 - a22 * b02 + a23 * b01) * inv_det,
+            (a10 * b10 - a11 * b08 + a13 * b06) * inv_det,
+            (-a00 * b10 + a01 * b08 - a03 * b06) * inv_det,
+            (a30 * b04 - a31 * b02 + a33 * b00) * inv_det,
+            (-a20 * b04 + a21 * b02 - a23 * b00) * inv_det,
+            (-a10 * b09 + a11 * b07 - a12 * b06) * inv_det,
+            (a00 * b09 - a01 * b07 + a02 * b06) * inv_det,
+            (-a30 * b03 + a31 * b01 - a32 * b00) * inv_det,
+            (a20 * b03 - a21 * b01 + a22 * b00) * inv_det,
+        ])
+    }
+
+    /// A perspective projection from the given clipping planes (`MatrixFrustum`)
+    pub fn frustum(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let rl = (right - left) as f32;
+        let tb = (top - bottom) as f32;
+        let fn_ = (far - near) as f32;
+        let (left, right, bottom, top) = (left as f32, right as f32, bottom as f32, top as f32);
+        let (near, far) = (near as f32, far as f32);
+
+        Self {
+            m0: (near * 2.0) / rl,
+            m5: (near * 2.0) / tb,
+            m8: (right + left) / rl,
+            m9: (top + bottom) / tb,
+            m10: -(far + near) / fn_,
+            m11: -1.0,
+            m14: -(far * near * 2.0) / fn_,
+            m15: 0.0,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// A perspective projection with the vertical field of view `fovy` in radians
+    /// (`MatrixPerspective`)
+    pub fn perspective(fovy: f64, aspect: f64, near: f64, far: f64) -> Self {
+        let top = near * (fovy * 0.5).tan();
+        let right = top * aspect;
+        Self::frustum(-right, right, -top, top, near, far)
+    }
+
+    /// An orthographic projection (`MatrixOrtho`)
+    pub fn ortho(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        let lr = (right - left) as f32;
+        let tb = (top - bottom) as f32;
+        let fn_ = (far - near) as f32;
+        let (left, right, bottom, top) = (left as f32, right as f32, bottom as f32, top as f32);
+        let (near, far) = (near as f32, far as f32);
+
+        Self {
+            m0: 2.0 / lr,
+            m5: 2.0 / tb,
+            m10: -2.0 / fn_,
+            m12: -(left + right) / lr,
+            m13: -(top + bottom) / tb,
+            m14: -(far + near) / fn_,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// A view matrix of a camera at `eye` looking at `target` (`MatrixLookAt`)
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self {
+        let vz = (eye - target).normalize();
+        let vx = up.cross(vz).normalize();
+        let vy = vz.cross(vx);
+
+        Self {
+            m0: vx.x,
+            m1: vy.x,
+            m2: vz.x,
+            m3: 0.0,
+            m4: vx.y,
+            m5: vy.y,
+            m6: vz.y,
+            m7: 0.0,
+            m8: vx.z,
+            m9: vy.z,
+            m10: vz.z,
+            m11: 0.0,
+            m12: -vx.dot(eye),
+            m13: -vy.dot(eye),
+            m14: -vz.dot(eye),
+            m15: 1.0,
+        }
+    }
+}
+
+impl From<ffi::Matrix> for Matrix {
+    fn from(mat: ffi::Matrix) -> Self {
+        Self {
+            m0: mat.m0,
+            m4: mat.m4,
+            m8: mat.m8,
+            m12: mat.m12,
+            m1: mat.m1,
+            m5: mat.m5,
+            m9: mat.m9,
+            m13: mat.m13,
+            m2: mat.m2,
+            m6: mat.m6,
+            m10: mat.m10,
+            m14: mat.m14,
+            m3: mat.m3,
+            m7: mat.m7,
+            m11: mat.m11,
+            m15: mat.m15,
+        }
+    }
+}
+
+impl From<Matrix> for ffi::Matrix {
+    fn from(mat: Matrix) -> ffi::Matrix {
+        ffi::Matrix {
+            m0: mat.m0,
+            m4: mat.m4,
+            m8: mat.m8,
+            m12: mat.m12,
+            m1: mat.m1,
+            m5: mat.m5,
+            m9: mat.m9,
+            m13: mat.m13,
+            m2: mat.m2,
+            m6: mat.m6,
+            m10: mat.m10,
+            m14: mat.m14,
+            m3: mat.m3,
+            m7: mat.m7,
+            m11: mat.m11,
+            m15: mat.m15,
+        }
+    }
+}
+
+impl Add for Matrix {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| lhs[i] + rhs[i]))
     }
 }
 
-// TODO: Implement Matrix
+impl Sub for Matrix {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| lhs[i] - rhs[i]))
+    }
+}
+
+// The usual matrix product, so that `(a * b) * v == a * (b * v)`
+impl Mul for Matrix {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (lhs, rhs) = (self.to_array(), rhs.to_array());
+        Self::from_array(std::array::from_fn(|i| {
+            let (col, row) = (i / 4, i % 4);
+            (0..4).map(|k| lhs[k * 4 + row] * rhs[col * 4 + k]).sum()
+        }))
+    }
+}
+
+impl MulAssign for Matrix {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+// Transform a point, that is, a vector with w = 1 (`Vector3Transform`)
+impl Mul<Vector3> for Matrix {
+    type Output = Vector3;
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        let Vector3 { x, y, z } = rhs;
+        Vector3 {
+            x: self.m0 * x + self.m4 * y + self.m8 * z + self.m12,
+            y: self.m1 * x + self.m5 * y + self.m9 * z + self.m13,
+            z: self.m2 * x + self.m6 * y + self.m10 * z + self.m14,
+        }
+    }
+}
+
+impl Mul<Vector4> for Matrix {
+    type Output = Vector4;
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        let Vector4 { x, y, z, w } = rhs;
+        Vector4 {
+            x: self.m0 * x + self.m4 * y + self.m8 * z + self.m12 * w,
+            y: self.m1 * x + self.m5 * y + self.m9 * z + self.m13 * w,
+            z: self.m2 * x + self.m6 * y + self.m10 * z + self.m14 * w,
+            w: self.m3 * x + self.m7 * y + self.m11 * z + self.m15 * w,
+        }
+    }
+}
@@ -6,6 +6,7 @@ macro_rules! to_bool {
         $fnt != 0
     };
 }
+#[macro_export]
 #[cfg(not(target_os = "windows"))]
 macro_rules! to_bool {
     ($fnt: expr) => {
@@ -16,23 +17,36 @@ macro_rules! to_bool {
 #[macro_export]
 macro_rules! impl_raylib_enum {
     ($enum_name: ident => $(($name: ident, $ffi: ident),)+) => {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub enum $enum_name {
             $($name,)+
         }
 
-        impl From<i32> for $enum_name {
+        impl $enum_name {
+            /// Every variant in the declared order
+            pub const ALL: &'static [$enum_name] = &[$($enum_name::$name,)+];
+
+            /// Iterate over every variant in the declared order
             #[inline]
-            fn from(ffi_enum: i32) -> $enum_name {
+            pub fn iter() -> std::iter::Copied<std::slice::Iter<'static, $enum_name>> {
+                Self::ALL.iter().copied()
+            }
+        }
+
+        impl TryFrom<i32> for $enum_name {
+            type Error = $crate::error::DiotekoErr;
+
+            #[inline]
+            fn try_from(ffi_enum: i32) -> Result<$enum_name, $crate::error::DiotekoErr> {
                 #[cfg(target_os = "windows")]
                 match ffi_enum {
-                    $(ffi::$ffi => $enum_name::$name,)+
-                    _ => panic!("[dioteko internal error]: Unreatchable"),
+                    $(ffi::$ffi => Ok($enum_name::$name),)+
+                    _ => Err($crate::error::DiotekoErr::UnknownEnumValue(ffi_enum)),
                 }
                 #[cfg(not(target_os = "windows"))]
                 match ffi_enum as u32 {
-                    $(ffi::$ffi => $enum_name::$name,)+
-                    _ => panic!("[dioteko internal error]: Unreatchable"),
+                    $(ffi::$ffi => Ok($enum_name::$name),)+
+                    _ => Err($crate::error::DiotekoErr::UnknownEnumValue(ffi_enum)),
                 }
             }
         }
@@ -50,21 +64,174 @@ macro_rules! impl_raylib_enum {
                 }
             }
         }
+
+        impl std::fmt::Display for $enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($enum_name::$name => write!(f, stringify!($name)),)+
+                }
+            }
+        }
+
+        // Parsing ignores the case, so that both "RightShift" and "Rightshift" work
+        impl std::str::FromStr for $enum_name {
+            type Err = $crate::error::DiotekoErr;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($name)) {
+                        return Ok($enum_name::$name);
+                    }
+                )+
+                Err($crate::error::DiotekoErr::ParseEnumErr(s.to_string()))
+            }
+        }
+
+        // Serialized by name, so that the files stay readable
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $enum_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+                name.parse().map_err(serde::de::Error::custom)
+            }
+        }
     };
 }
 
 #[macro_export]
 macro_rules! impl_vec {
     ($vec_name: ident | $($components: ident),+) => {
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $vec_name {
             $(pub $components: f32,)+
         }
 
         impl $vec_name {
+            /// The number of components
+            pub const LEN: usize = 0 $(+ $crate::impl_vec!(@one $components))+;
+            pub const ZERO: Self = Self { $($components: 0.0,)+ };
+            pub const ONE: Self = Self { $($components: 1.0,)+ };
+
             pub fn new($($components: f32,)+) -> Self {
                 Self { $($components,)+ }
             }
+
+            #[inline]
+            pub fn dot(self, rhs: Self) -> f32 {
+                0.0 $(+ self.$components * rhs.$components)+
+            }
+
+            #[inline]
+            pub fn length(self) -> f32 {
+                self.length_sqr().sqrt()
+            }
+
+            #[inline]
+            pub fn length_sqr(self) -> f32 {
+                self.dot(self)
+            }
+
+            /// Scale the vector to the length 1. The zero vector stays zero.
+            pub fn normalize(self) -> Self {
+                let length = self.length();
+                if length > 0.0 {
+                    self / length
+                } else {
+                    self
+                }
+            }
+
+            #[inline]
+            pub fn distance(self, rhs: Self) -> f32 {
+                (rhs - self).length()
+            }
+
+            #[inline]
+            pub fn distance_sqr(self, rhs: Self) -> f32 {
+                (rhs - self).length_sqr()
+            }
+
+            /// Linear interpolation where `amount` 0 gives `self` and 1 gives `rhs`
+            #[inline]
+            pub fn lerp(self, rhs: Self, amount: f32) -> Self {
+                self + (rhs - self) * amount
+            }
+
+            /// Reflect the vector on the plane with the given normal
+            pub fn reflect(self, normal: Self) -> Self {
+                self - normal * (2.0 * self.dot(normal))
+            }
+
+            /// Clamp every component between the ones of `min` and `max`
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self {
+                    $($components: self.$components.clamp(min.$components, max.$components),)+
+                }
+            }
+
+            /// Clamp the length of the vector between `min` and `max`
+            pub fn clamp_length(self, min: f32, max: f32) -> Self {
+                let length = self.length();
+                if length == 0.0 {
+                    self
+                } else if length < min {
+                    self * (min / length)
+                } else if length > max {
+                    self * (max / length)
+                } else {
+                    self
+                }
+            }
+
+            /// The component-wise minimum
+            pub fn min(self, rhs: Self) -> Self {
+                Self {
+                    $($components: self.$components.min(rhs.$components),)+
+                }
+            }
+
+            /// The component-wise maximum
+            pub fn max(self, rhs: Self) -> Self {
+                Self {
+                    $($components: self.$components.max(rhs.$components),)+
+                }
+            }
+
+            /// Move towards `target` by at most `max_distance` without passing it
+            pub fn move_towards(self, target: Self, max_distance: f32) -> Self {
+                let delta = target - self;
+                let distance_sqr = delta.length_sqr();
+                if distance_sqr == 0.0
+                    || (max_distance >= 0.0 && distance_sqr <= max_distance * max_distance)
+                {
+                    return target;
+                }
+
+                self + delta * (max_distance / distance_sqr.sqrt())
+            }
+
+            /// Check whether every component is nearly equal with a tolerance relative
+            /// to its magnitude (`Vector2Equals`)
+            pub fn approx_eq(self, rhs: Self) -> bool {
+                let eq = |lhs: f32, rhs: f32| {
+                    (lhs - rhs).abs() <= EPSILON * 1.0f32.max(lhs.abs().max(rhs.abs()))
+                };
+                true $(&& eq(self.$components, rhs.$components))+
+            }
+
+            /// Get the components as an array
+            #[inline]
+            pub fn to_array(self) -> [f32; Self::LEN] {
+                [$(self.$components,)+]
+            }
         }
 
         impl From<ffi::$vec_name> for $vec_name {
@@ -85,6 +252,58 @@ macro_rules! impl_vec {
             }
         }
 
+        impl From<[f32; $vec_name::LEN]> for $vec_name {
+            #[inline]
+            fn from(array: [f32; $vec_name::LEN]) -> Self {
+                let [$($components,)+] = array;
+                Self { $($components,)+ }
+            }
+        }
+
+        impl From<$vec_name> for [f32; $vec_name::LEN] {
+            #[inline]
+            fn from(vec: $vec_name) -> Self {
+                vec.to_array()
+            }
+        }
+
+        impl From<($($crate::impl_vec!(@f32 $components),)+)> for $vec_name {
+            #[inline]
+            fn from(tuple: ($($crate::impl_vec!(@f32 $components),)+)) -> Self {
+                let ($($components,)+) = tuple;
+                Self { $($components,)+ }
+            }
+        }
+
+        impl From<$vec_name> for ($($crate::impl_vec!(@f32 $components),)+) {
+            #[inline]
+            fn from(vec: $vec_name) -> Self {
+                ($(vec.$components,)+)
+            }
+        }
+
+        impl Index<usize> for $vec_name {
+            type Output = f32;
+
+            fn index(&self, index: usize) -> &f32 {
+                [$(&self.$components,)+][index]
+            }
+        }
+
+        impl IndexMut<usize> for $vec_name {
+            fn index_mut(&mut self, index: usize) -> &mut f32 {
+                [$(&mut self.$components,)+]
+                    .into_iter()
+                    .nth(index)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "index out of bounds: the len is {} but the index is {index}",
+                            Self::LEN
+                        )
+                    })
+            }
+        }
+
         // Addition of Vectors
         impl Add for $vec_name {
             type Output = Self;
@@ -119,6 +338,15 @@ macro_rules! impl_vec {
             }
         }
 
+        impl Neg for $vec_name {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self {
+                    $($components: -self.$components,)+
+                }
+            }
+        }
+
         // Scalar multiplication of Vectors
         impl Mul<$vec_name> for f32 {
             type Output = $vec_name;
@@ -128,5 +356,320 @@ macro_rules! impl_vec {
                 }
             }
         }
+
+        impl Mul<f32> for $vec_name {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: f32) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        // Component-wise multiplication of Vectors
+        impl Mul for $vec_name {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($components: self.$components * rhs.$components,)+
+                }
+            }
+        }
+
+        impl Div<f32> for $vec_name {
+            type Output = Self;
+            fn div(self, rhs: f32) -> Self::Output {
+                Self {
+                    $($components: self.$components / rhs,)+
+                }
+            }
+        }
+
+        // Component-wise division of Vectors
+        impl Div for $vec_name {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($components: self.$components / rhs.$components,)+
+                }
+            }
+        }
+
+        impl MulAssign<f32> for $vec_name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: f32) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign for $vec_name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<f32> for $vec_name {
+            #[inline]
+            fn div_assign(&mut self, rhs: f32) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl DivAssign for $vec_name {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Sum for $vec_name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $vec_name> for $vec_name {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    };
+    (@one $_component: ident) => {
+        1
+    };
+    (@f32 $_component: ident) => {
+        f32
+    };
+}
+
+#[macro_export]
+macro_rules! impl_raylib_flags {
+    ($flags_name: ident => $(($name: ident, $ffi: ident),)+) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $flags_name(u32);
+
+        impl $flags_name {
+            $(
+                #[cfg(target_os = "windows")]
+                pub const $name: Self = Self(ffi::$ffi as u32);
+                #[cfg(not(target_os = "windows"))]
+                pub const $name: Self = Self(ffi::$ffi);
+            )+
+
+            /// Every named flag with its name
+            const NAMED: &'static [(&'static str, $flags_name)] = &[
+                $((stringify!($name), $flags_name::$name),)+
+            ];
+
+            #[inline]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            #[inline]
+            pub const fn all() -> Self {
+                Self(0 $(| $flags_name::$name.0)+)
+            }
+
+            #[inline]
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+
+            /// Returns `None` if `bits` contains a bit which is not a named flag
+            #[inline]
+            pub const fn from_bits(bits: u32) -> Option<Self> {
+                if bits & !Self::all().0 == 0 {
+                    Some(Self(bits))
+                } else {
+                    None
+                }
+            }
+
+            /// Drops every bit which is not a named flag
+            #[inline]
+            pub const fn from_bits_truncate(bits: u32) -> Self {
+                Self(bits & Self::all().0)
+            }
+
+            #[inline]
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Check whether every flag of `other` is set in `self`
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Check whether `self` and `other` share at least one flag
+            #[inline]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            #[inline]
+            pub const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            #[inline]
+            pub const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            #[inline]
+            pub const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            #[inline]
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            #[inline]
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            #[inline]
+            pub fn toggle(&mut self, other: Self) {
+                self.0 ^= other.0;
+            }
+
+            /// Iterate over the named flags which are set in `self`
+            pub fn iter(self) -> impl Iterator<Item = Self> {
+                Self::NAMED
+                    .iter()
+                    .map(|&(_, flag)| flag)
+                    .filter(move |&flag| !flag.is_empty() && self.contains(flag))
+            }
+
+            /// Iterate over the names of the flags which are set in `self`
+            pub fn iter_names(self) -> impl Iterator<Item = &'static str> {
+                Self::NAMED
+                    .iter()
+                    .filter(move |&&(_, flag)| !flag.is_empty() && self.contains(flag))
+                    .map(|&(name, _)| name)
+            }
+        }
+
+        impl std::fmt::Debug for $flags_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}(", stringify!($flags_name))?;
+                for (i, name) in self.iter_names().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{}", name)?;
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl std::ops::BitOr for $flags_name {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self::Output {
+                self.union(rhs)
+            }
+        }
+
+        impl std::ops::BitAnd for $flags_name {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self::Output {
+                self.intersection(rhs)
+            }
+        }
+
+        impl std::ops::BitXor for $flags_name {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl std::ops::Sub for $flags_name {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                self.difference(rhs)
+            }
+        }
+
+        impl std::ops::Not for $flags_name {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self::Output {
+                Self::from_bits_truncate(!self.0)
+            }
+        }
+
+        impl std::ops::BitOrAssign for $flags_name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.insert(rhs);
+            }
+        }
+
+        impl std::ops::BitAndAssign for $flags_name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl std::ops::BitXorAssign for $flags_name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: Self) {
+                self.toggle(rhs);
+            }
+        }
+
+        impl std::ops::SubAssign for $flags_name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.remove(rhs);
+            }
+        }
+
+        impl std::iter::FromIterator<$flags_name> for $flags_name {
+            fn from_iter<I: IntoIterator<Item = $flags_name>>(iter: I) -> Self {
+                iter.into_iter().fold(Self::empty(), |acc, flag| acc | flag)
+            }
+        }
+
+        impl std::iter::Extend<$flags_name> for $flags_name {
+            fn extend<I: IntoIterator<Item = $flags_name>>(&mut self, iter: I) {
+                for flag in iter {
+                    self.insert(flag);
+                }
+            }
+        }
+
+        // Serialized as the bits, which must all be named flags
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $flags_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $flags_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let bits = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+                Self::from_bits(bits).ok_or_else(|| {
+                    serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(bits.into()),
+                        &concat!("bits of ", stringify!($flags_name)),
+                    )
+                })
+            }
+        }
     };
 }
@@ -1,11 +1,23 @@
 #[macro_use]
 mod macros;
 
+pub mod action;
+pub mod automation;
+pub mod clipboard;
 pub mod color;
+pub mod combo;
+pub mod event;
+pub mod gamepad;
+pub mod input_state;
+mod interop;
 pub mod keyboard;
 pub mod linalg;
+pub mod monitor;
 pub mod mouse;
 pub mod npatchinfo;
 pub mod rectangle;
+pub mod text_input;
+pub mod touch;
+pub mod trace_log;
 pub mod utility;
 pub mod window;
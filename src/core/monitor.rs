@@ -0,0 +1,103 @@
+use std::ffi::CStr;
+
+use crate::core::linalg::Vector2;
+use crate::ffi;
+
+/// A handle of a connected monitor
+///
+/// Monitor functions need an initialized window, so every query should be called
+/// after `WindowBuilder::build`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Monitor(i32);
+
+/// Iterate over every connected monitor
+pub fn monitors() -> impl Iterator<Item = Monitor> {
+    (0..Monitor::count()).map(Monitor)
+}
+
+impl Monitor {
+    /// Make a monitor handle without checking that the monitor exists.
+    /// This is useful with `WindowBuilder::on_monitor` because monitors cannot be
+    /// enumerated before the window is initialized.
+    #[inline]
+    pub const fn from_index(index: i32) -> Self {
+        Self(index)
+    }
+
+    /// Get the monitor with the given index. Returns `None` if there is no such monitor
+    pub fn get(index: i32) -> Option<Self> {
+        (0..Self::count()).contains(&index).then_some(Self(index))
+    }
+
+    /// Get the primary monitor
+    #[inline]
+    pub fn primary() -> Self {
+        Self(0)
+    }
+
+    /// Get the monitor where the window is placed
+    pub fn current() -> Self {
+        // SAFETY: ffi
+        Self(unsafe { ffi::GetCurrentMonitor() })
+    }
+
+    /// Get the number of connected monitors
+    pub fn count() -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorCount() }
+    }
+
+    #[inline]
+    pub fn index(self) -> i32 {
+        self.0
+    }
+
+    /// Get the human-readable, UTF-8 encoded name of the monitor
+    pub fn name(self) -> String {
+        // SAFETY: ffi
+        let name = unsafe { ffi::GetMonitorName(self.0) };
+        if name.is_null() {
+            return String::new();
+        }
+
+        // SAFETY: raylib returns a nul-terminated string owned by GLFW
+        unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Get the position of the monitor in the virtual desktop
+    pub fn position(self) -> Vector2 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorPosition(self.0) }.into()
+    }
+
+    /// Get the width of the current video mode of the monitor
+    pub fn width(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorWidth(self.0) }
+    }
+
+    /// Get the height of the current video mode of the monitor
+    pub fn height(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorHeight(self.0) }
+    }
+
+    /// Get the physical width of the monitor in millimetres
+    pub fn physical_width(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorPhysicalWidth(self.0) }
+    }
+
+    /// Get the physical height of the monitor in millimetres
+    pub fn physical_height(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorPhysicalHeight(self.0) }
+    }
+
+    pub fn refresh_rate(self) -> i32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetMonitorRefreshRate(self.0) }
+    }
+}
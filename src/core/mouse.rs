@@ -36,11 +36,60 @@ pub fn set_mouse_scale(scale_x: f32, scale_y: f32) {
     unsafe { ffi::SetMouseScale(scale_x, scale_y) }
 }
 
+/// Get the mouse wheel movement of the dominant axis
 pub fn get_mouse_wheel_move() -> f32 {
     // SAFETY: ffi
     unsafe { ffi::GetMouseWheelMove() }
 }
 
+/// Get the mouse wheel movement of both axes
+pub fn get_mouse_wheel_move_v() -> Vector2 {
+    // SAFETY: ffi
+    unsafe { ffi::GetMouseWheelMoveV() }.into()
+}
+
+/// Cursor Module
+///
+/// This module controls the visibility of the cursor and whether it is locked in
+/// the window. The shape of the cursor is changed with `MouseCursor::set`.
+pub mod cursor {
+    use crate::ffi;
+
+    pub fn show() {
+        // SAFETY: ffi
+        unsafe { ffi::ShowCursor() }
+    }
+
+    pub fn hide() {
+        // SAFETY: ffi
+        unsafe { ffi::HideCursor() }
+    }
+
+    pub fn is_hidden() -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsCursorHidden()) }
+    }
+
+    /// Hide the cursor and lock it in the window.
+    /// While locked, `get_mouse_delta` gives the relative movement of the mouse.
+    pub fn lock() {
+        // SAFETY: ffi
+        unsafe { ffi::DisableCursor() }
+    }
+
+    /// Show the cursor and release it from the window
+    pub fn unlock() {
+        // SAFETY: ffi
+        unsafe { ffi::EnableCursor() }
+    }
+
+    /// Check whether the cursor is on the window
+    pub fn is_on_screen() -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsCursorOnScreen()) }
+    }
+}
+
 impl_raylib_enum![
     MouseButton =>
     (Left, MouseButton_MOUSE_BUTTON_LEFT),
@@ -88,3 +137,11 @@ impl_raylib_enum![
     (ResizeAll, MouseCursor_MOUSE_CURSOR_RESIZE_ALL),
     (NotAllowed, MouseCursor_MOUSE_CURSOR_NOT_ALLOWED),
 ];
+
+impl MouseCursor {
+    /// Change the shape of the cursor
+    pub fn set(self) {
+        // SAFETY: ffi
+        unsafe { ffi::SetMouseCursor(self.into()) }
+    }
+}
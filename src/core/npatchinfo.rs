@@ -2,6 +2,7 @@ use crate::core::rectangle::Rectangle;
 use crate::ffi;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NPatchInfo {
     pub source: Rectangle,
     pub left: i32,
@@ -0,0 +1,434 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::core::clipboard;
+use crate::core::event::Event;
+use crate::core::input_state::InputState;
+use crate::core::keyboard::Key;
+
+/// An editable single-line text buffer
+///
+/// The cursor moves by grapheme clusters, so a combined character such as an emoji
+/// with a skin tone modifier is never split. Call `update` once per frame to consume
+/// the typed characters and the editing keys, or feed every event of
+/// `Window::events` to `handle_event`.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    text: String,
+    // Both are byte offsets on grapheme boundaries
+    cursor: usize,
+    anchor: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: Edit,
+    // The modifier keys held according to the events given to `handle_event`
+    held_modifiers: Vec<Key>,
+}
+
+#[derive(Debug, Clone)]
+struct Snapshot {
+    text: String,
+    cursor: usize,
+    anchor: Option<usize>,
+}
+
+// Consecutive edits of the same kind are merged into one undo step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Edit {
+    #[default]
+    None,
+    Typing,
+    Deleting,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make a text input with the cursor at the end of `text`
+    pub fn with_text(text: &str) -> Self {
+        let mut input = Self::new();
+        input.text = sanitize(text);
+        input.cursor = input.text.len();
+        input
+    }
+
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the whole text. This can be undone.
+    pub fn set_text(&mut self, text: &str) {
+        self.push_undo(Edit::None);
+        self.text = sanitize(text);
+        self.cursor = self.text.len();
+        self.anchor = None;
+    }
+
+    /// The byte offset of the cursor in `text`
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The byte range of the selected text, if any
+    pub fn selection(&self) -> Option<Range<usize>> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor => {
+                Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.text[range])
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.text.len();
+        self.last_edit = Edit::None;
+    }
+
+    /// Insert `text` at the cursor, replacing the selection
+    pub fn insert(&mut self, text: &str) {
+        let text = sanitize(text);
+        if text.is_empty() {
+            return;
+        }
+
+        self.push_undo(Edit::Typing);
+        self.delete_selection();
+        self.text.insert_str(self.cursor, &text);
+        self.cursor += text.len();
+    }
+
+    /// Delete the selection, or the grapheme (word with `word`) before the cursor
+    pub fn backspace(&mut self, word: bool) {
+        let start = match self.selection() {
+            Some(_) => None,
+            None if word => Some(self.prev_word()),
+            None => Some(self.prev_grapheme()),
+        };
+        self.delete_to(start);
+    }
+
+    /// Delete the selection, or the grapheme (word with `word`) after the cursor
+    pub fn delete(&mut self, word: bool) {
+        let end = match self.selection() {
+            Some(_) => None,
+            None if word => Some(self.next_word()),
+            None => Some(self.next_grapheme()),
+        };
+        self.delete_to(end);
+    }
+
+    /// Move the cursor by one grapheme. With `select`, the selection is extended.
+    pub fn move_left(&mut self, select: bool) {
+        let target = match self.selection() {
+            Some(range) if !select => range.start,
+            _ => self.prev_grapheme(),
+        };
+        self.move_to(target, select);
+    }
+
+    pub fn move_right(&mut self, select: bool) {
+        let target = match self.selection() {
+            Some(range) if !select => range.end,
+            _ => self.next_grapheme(),
+        };
+        self.move_to(target, select);
+    }
+
+    /// Move the cursor to the start of the previous word
+    pub fn move_word_left(&mut self, select: bool) {
+        self.move_to(self.prev_word(), select);
+    }
+
+    /// Move the cursor to the end of the next word
+    pub fn move_word_right(&mut self, select: bool) {
+        self.move_to(self.next_word(), select);
+    }
+
+    pub fn move_home(&mut self, select: bool) {
+        self.move_to(0, select);
+    }
+
+    pub fn move_end(&mut self, select: bool) {
+        self.move_to(self.text.len(), select);
+    }
+
+    /// Returns false if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.redo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns false if there is nothing to redo
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.undo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Copy the selection into the system clipboard
+    pub fn copy(&self) {
+        if let Some(text) = self.selected_text() {
+            // The text never contains nul characters
+            let _ = clipboard::set(text);
+        }
+    }
+
+    /// Move the selection into the system clipboard
+    pub fn cut(&mut self) {
+        if self.selection().is_some() {
+            self.copy();
+            self.delete_to(None);
+        }
+    }
+
+    /// Insert the text of the system clipboard at the cursor
+    pub fn paste(&mut self) {
+        if let Some(text) = clipboard::get() {
+            self.paste_text(&text);
+        }
+    }
+
+    /// Insert `text` at the cursor as a paste, which is an undo step on its own
+    pub fn paste_text(&mut self, text: &str) {
+        // Neither merged into the typing before nor after
+        self.last_edit = Edit::None;
+        self.insert(text);
+        self.last_edit = Edit::None;
+    }
+
+    /// Consume the typed characters and the editing keys of this frame.
+    /// Returns true if the text has been changed.
+    pub fn update(&mut self) -> bool {
+        self.update_from(&InputState::capture())
+    }
+
+    /// Same as `update`, but reads a captured input state
+    pub fn update_from(&mut self, input: &InputState) -> bool {
+        let before = self.text.clone();
+
+        for &ch in input.chars() {
+            self.insert(ch.encode_utf8(&mut [0; 4]));
+        }
+
+        let ctrl = input.is_key_down(Key::LeftControl) || input.is_key_down(Key::Rightcontrol);
+        let shift = input.is_key_down(Key::LeftShift) || input.is_key_down(Key::Rightshift);
+        for key in EDITING_KEYS {
+            if input.is_key_pressed(key) || input.is_key_pressed_repeat(key) {
+                self.handle_key(key, ctrl, shift);
+            }
+        }
+
+        before != self.text
+    }
+
+    /// Handle an event taken from `Window::events`.
+    /// Returns true if the event has been consumed.
+    ///
+    /// Ctrl and shift are tracked from the key events, so every event should be given
+    /// including the ones of the modifier keys, which are never consumed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match *event {
+            Event::Char(ch) => {
+                self.insert(ch.encode_utf8(&mut [0; 4]));
+                true
+            }
+            Event::KeyDown(key) if MODIFIER_KEYS.contains(&key) => {
+                if !self.held_modifiers.contains(&key) {
+                    self.held_modifiers.push(key);
+                }
+                false
+            }
+            Event::KeyUp(key) => {
+                self.held_modifiers.retain(|&held| held != key);
+                false
+            }
+            // The key up events are lost while unfocused
+            Event::FocusChanged(false) => {
+                self.held_modifiers.clear();
+                false
+            }
+            Event::KeyDown(key) | Event::KeyRepeat(key) if EDITING_KEYS.contains(&key) => {
+                let ctrl = self.is_modifier_held(Key::LeftControl, Key::Rightcontrol);
+                let shift = self.is_modifier_held(Key::LeftShift, Key::Rightshift);
+                self.handle_key(key, ctrl, shift)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_modifier_held(&self, left: Key, right: Key) -> bool {
+        self.held_modifiers
+            .iter()
+            .any(|&held| held == left || held == right)
+    }
+
+    fn handle_key(&mut self, key: Key, ctrl: bool, shift: bool) -> bool {
+        match key {
+            Key::Left if ctrl => self.move_word_left(shift),
+            Key::Left => self.move_left(shift),
+            Key::Right if ctrl => self.move_word_right(shift),
+            Key::Right => self.move_right(shift),
+            Key::Home => self.move_home(shift),
+            Key::End => self.move_end(shift),
+            Key::Backspace => self.backspace(ctrl),
+            Key::Delete => self.delete(ctrl),
+            Key::A if ctrl => self.select_all(),
+            Key::C if ctrl => self.copy(),
+            Key::X if ctrl => self.cut(),
+            Key::V if ctrl => self.paste(),
+            Key::Z if ctrl && shift => {
+                self.redo();
+            }
+            Key::Z if ctrl => {
+                self.undo();
+            }
+            Key::Y if ctrl => {
+                self.redo();
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn move_to(&mut self, target: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = target;
+        self.last_edit = Edit::None;
+    }
+
+    // Deletes the selection if `other_end` is None, else the text between the cursor
+    // and `other_end`
+    fn delete_to(&mut self, other_end: Option<usize>) {
+        let range = match other_end {
+            Some(end) => end.min(self.cursor)..end.max(self.cursor),
+            None => match self.selection() {
+                Some(range) => range,
+                None => return,
+            },
+        };
+        if range.is_empty() {
+            return;
+        }
+
+        self.push_undo(Edit::Deleting);
+        self.text.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.anchor = None;
+    }
+
+    fn delete_selection(&mut self) {
+        if let Some(range) = self.selection() {
+            self.text.replace_range(range.clone(), "");
+            self.cursor = range.start;
+        }
+        self.anchor = None;
+    }
+
+    fn prev_grapheme(&self) -> usize {
+        self.text[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_grapheme(&self) -> usize {
+        self.text[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map_or(self.cursor, |g| self.cursor + g.len())
+    }
+
+    fn prev_word(&self) -> usize {
+        self.text[..self.cursor]
+            .split_word_bound_indices()
+            .rev()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_word(&self) -> usize {
+        self.text[self.cursor..]
+            .split_word_bound_indices()
+            .find(|(_, word)| !word.trim().is_empty())
+            .map_or(self.text.len(), |(i, word)| self.cursor + i + word.len())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            text: self.text.clone(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.text = snapshot.text;
+        self.cursor = snapshot.cursor;
+        self.anchor = snapshot.anchor;
+        self.last_edit = Edit::None;
+    }
+
+    fn push_undo(&mut self, edit: Edit) {
+        if edit == Edit::None || edit != self.last_edit {
+            let snapshot = self.snapshot();
+            self.undo_stack.push(snapshot);
+        }
+        self.redo_stack.clear();
+        self.last_edit = edit;
+    }
+}
+
+const EDITING_KEYS: [Key; 12] = [
+    Key::Left,
+    Key::Right,
+    Key::Home,
+    Key::End,
+    Key::Backspace,
+    Key::Delete,
+    Key::A,
+    Key::C,
+    Key::X,
+    Key::V,
+    Key::Z,
+    Key::Y,
+];
+
+const MODIFIER_KEYS: [Key; 4] = [
+    Key::LeftControl,
+    Key::Rightcontrol,
+    Key::LeftShift,
+    Key::Rightshift,
+];
+
+// Single-line text can not contain control characters (including nul)
+fn sanitize(text: &str) -> String {
+    text.chars().filter(|ch| !ch.is_control()).collect()
+}
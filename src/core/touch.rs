@@ -0,0 +1,122 @@
+use crate::core::linalg::Vector2;
+use crate::ffi;
+
+/// An active touch point
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TouchPoint {
+    pub id: i32,
+    pub position: Vector2,
+}
+
+/// Get the X coordinate of the first touch point
+pub fn get_touch_x() -> i32 {
+    // SAFETY: ffi
+    unsafe { ffi::GetTouchX() }
+}
+
+/// Get the Y coordinate of the first touch point
+pub fn get_touch_y() -> i32 {
+    // SAFETY: ffi
+    unsafe { ffi::GetTouchY() }
+}
+
+pub fn get_touch_point_count() -> i32 {
+    // SAFETY: ffi
+    unsafe { ffi::GetTouchPointCount() }
+}
+
+/// Get the touch point of the given index. Returns `None` if there is no such point
+pub fn get_touch_point(index: i32) -> Option<TouchPoint> {
+    if !(0..get_touch_point_count()).contains(&index) {
+        return None;
+    }
+
+    // SAFETY: ffi
+    unsafe {
+        Some(TouchPoint {
+            id: ffi::GetTouchPointId(index),
+            position: ffi::GetTouchPosition(index).into(),
+        })
+    }
+}
+
+/// Iterate over every active touch point of this frame
+pub fn touch_points() -> impl Iterator<Item = TouchPoint> {
+    (0..get_touch_point_count()).filter_map(get_touch_point)
+}
+
+impl_raylib_flags![
+    Gesture =>
+    (NONE       , Gesture_GESTURE_NONE),
+    (TAP        , Gesture_GESTURE_TAP),
+    (DOUBLETAP  , Gesture_GESTURE_DOUBLETAP),
+    (HOLD       , Gesture_GESTURE_HOLD),
+    (DRAG       , Gesture_GESTURE_DRAG),
+    (SWIPE_RIGHT, Gesture_GESTURE_SWIPE_RIGHT),
+    (SWIPE_LEFT , Gesture_GESTURE_SWIPE_LEFT),
+    (SWIPE_UP   , Gesture_GESTURE_SWIPE_UP),
+    (SWIPE_DOWN , Gesture_GESTURE_SWIPE_DOWN),
+    (PINCH_IN   , Gesture_GESTURE_PINCH_IN),
+    (PINCH_OUT  , Gesture_GESTURE_PINCH_OUT),
+];
+
+impl Gesture {
+    /// Enable only the given gestures to be detected
+    pub fn set_enabled(gestures: Self) {
+        // SAFETY: ffi
+        unsafe { ffi::SetGesturesEnabled(gestures.bits()) }
+    }
+
+    /// Check whether any of the given gestures is detected in this frame
+    pub fn is_detected(self) -> bool {
+        self.iter().any(|gesture| {
+            // SAFETY: ffi
+            #[cfg(target_os = "windows")]
+            unsafe {
+                to_bool!(ffi::IsGestureDetected(gesture.bits() as i32))
+            }
+            #[cfg(not(target_os = "windows"))]
+            unsafe {
+                to_bool!(ffi::IsGestureDetected(gesture.bits()))
+            }
+        })
+    }
+
+    /// Get the latest detected gesture
+    pub fn detected() -> Self {
+        // SAFETY: ffi
+        Self::from_bits_truncate(unsafe { ffi::GetGestureDetected() } as u32)
+    }
+
+    /// Get the duration of the `HOLD` gesture in seconds
+    pub fn hold_duration() -> f32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGestureHoldDuration() }
+    }
+
+    /// Get the movement of the `DRAG` gesture
+    pub fn drag_vector() -> Vector2 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGestureDragVector() }.into()
+    }
+
+    /// Get the angle of the `DRAG` gesture in degrees
+    pub fn drag_angle() -> f32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGestureDragAngle() }
+    }
+
+    /// Get the distance between the two touch points of the `PINCH_IN`/`PINCH_OUT`
+    /// gesture
+    pub fn pinch_vector() -> Vector2 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGesturePinchVector() }.into()
+    }
+
+    /// Get the angle of the `PINCH_IN`/`PINCH_OUT` gesture in degrees
+    pub fn pinch_angle() -> f32 {
+        // SAFETY: ffi
+        unsafe { ffi::GetGesturePinchAngle() }
+    }
+}
@@ -0,0 +1,135 @@
+//! Trace Log Module
+//!
+//! raylib reports what it is doing (e.g. the OpenGL version or every loaded texture)
+//! by printing to the standard output. The messages below a level can be silenced with
+//! `set_trace_log_level`, and every message can be taken over with
+//! `set_trace_log_callback`. With the `log` feature, `forward_to_log` sends them to
+//! the `log` facade with the target `raylib`, so that they can be filtered like any
+//! other log.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use crate::error;
+use crate::ffi;
+
+impl_raylib_enum![
+    TraceLogLevel =>
+    (All, TraceLogLevel_LOG_ALL),
+    (Trace, TraceLogLevel_LOG_TRACE),
+    (Debug, TraceLogLevel_LOG_DEBUG),
+    (Info, TraceLogLevel_LOG_INFO),
+    (Warning, TraceLogLevel_LOG_WARNING),
+    (Error, TraceLogLevel_LOG_ERROR),
+    (Fatal, TraceLogLevel_LOG_FATAL),
+    (None, TraceLogLevel_LOG_NONE),
+];
+
+type Callback = Box<dyn FnMut(TraceLogLevel, &str) + Send>;
+
+// The outer lock is only held to swap or clone the callback, so that the callback may
+// replace itself. The inner lock is held while it runs.
+static CALLBACK: Mutex<Option<Arc<Mutex<Callback>>>> = Mutex::new(None);
+
+// Longer messages are truncated
+const MAX_MESSAGE_LENGTH: usize = 1024;
+
+#[cfg(target_os = "windows")]
+type VaList = ffi::va_list;
+#[cfg(not(target_os = "windows"))]
+type VaList = *mut ffi::__va_list_tag;
+
+// The va_list is passed exactly as raylib gives it
+#[cfg_attr(
+    all(target_os = "windows", target_env = "msvc"),
+    link(name = "legacy_stdio_definitions")
+)]
+extern "C" {
+    fn vsnprintf(buffer: *mut c_char, size: usize, format: *const c_char, args: VaList) -> c_int;
+}
+
+/// Only show the messages at or above the given level
+pub fn set_trace_log_level(level: TraceLogLevel) {
+    // SAFETY: ffi
+    unsafe { ffi::SetTraceLogLevel(level.into()) }
+}
+
+/// Log a message through raylib, so that it goes wherever raylib messages go.
+/// Fails if `message` contains a nul character.
+pub fn trace_log(level: TraceLogLevel, message: &str) -> error::Result<()> {
+    let message = CString::new(message)?;
+
+    // SAFETY: ffi
+    // SAFETY: the message is passed as an argument, so `%` in it is not a format
+    unsafe { ffi::TraceLog(level.into(), c"%s".as_ptr(), message.as_ptr()) }
+
+    Ok(())
+}
+
+/// Handle every raylib message with `callback` instead of printing it.
+/// The message is already formatted and has no trailing newline.
+///
+/// The callback may call `set_trace_log_callback` or `reset_trace_log_callback`,
+/// which take effect from the next message. Messages logged while the callback runs,
+/// e.g. with `trace_log` inside it or from another thread, are dropped.
+pub fn set_trace_log_callback(callback: impl FnMut(TraceLogLevel, &str) + Send + 'static) {
+    let callback: Callback = Box::new(callback);
+    let callback = Arc::new(Mutex::new(callback));
+    *CALLBACK.lock().unwrap_or_else(|err| err.into_inner()) = Some(callback);
+
+    // SAFETY: ffi
+    unsafe { ffi::SetTraceLogCallback(Some(trace_log_callback)) }
+}
+
+/// Let raylib print its messages again
+pub fn reset_trace_log_callback() {
+    // SAFETY: ffi
+    unsafe { ffi::SetTraceLogCallback(None) }
+
+    *CALLBACK.lock().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// Send every raylib message to the `log` facade with the target `raylib`
+#[cfg(feature = "log")]
+pub fn forward_to_log() {
+    set_trace_log_callback(|level, message| {
+        let level = match level {
+            TraceLogLevel::Trace | TraceLogLevel::All => log::Level::Trace,
+            TraceLogLevel::Debug => log::Level::Debug,
+            TraceLogLevel::Info => log::Level::Info,
+            TraceLogLevel::Warning => log::Level::Warn,
+            TraceLogLevel::Error | TraceLogLevel::Fatal => log::Level::Error,
+            TraceLogLevel::None => return,
+        };
+        log::log!(target: "raylib", level, "{message}");
+    });
+}
+
+unsafe extern "C" fn trace_log_callback(level: c_int, text: *const c_char, args: VaList) {
+    let mut buffer = [0 as c_char; MAX_MESSAGE_LENGTH];
+    // SAFETY: raylib gives a printf format with its arguments, and vsnprintf always
+    // terminates the buffer with nul
+    let message = unsafe {
+        vsnprintf(buffer.as_mut_ptr(), buffer.len(), text, args);
+        CStr::from_ptr(buffer.as_ptr()).to_string_lossy()
+    };
+    let level = TraceLogLevel::try_from(level).unwrap_or(TraceLogLevel::Info);
+
+    let Some(callback) = CALLBACK
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+    else {
+        return;
+    };
+
+    // The lock is busy when the callback logs through raylib itself
+    let guard = callback.try_lock();
+    if let Ok(mut callback) = guard {
+        // Unwinding into raylib is undefined behavior, so a panic only loses the
+        // message
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| (*callback)(level, &message)));
+    }
+}
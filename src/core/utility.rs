@@ -28,6 +28,12 @@ pub mod time {
         // SAFETY: ffi
         unsafe { ffi::GetTime() }
     }
+
+    /// Halt the program execution for the given seconds
+    pub fn wait_time(seconds: f64) {
+        // SAFETY: ffi
+        unsafe { ffi::WaitTime(seconds) }
+    }
 }
 
 pub mod random {
@@ -1,10 +1,35 @@
-use std::cell::Cell;
 use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
+use crate::core::event::{self, Event};
 use crate::core::keyboard::Key;
+use crate::core::linalg::Vector2;
+use crate::core::monitor::Monitor;
 use crate::error::{self, DiotekoErr};
 use crate::ffi;
 use crate::painter::textures::image;
+use crate::painter::Painter;
+
+impl_raylib_flags![
+    WindowFlags =>
+    (VSYNC_HINT              , ConfigFlags_FLAG_VSYNC_HINT),
+    (FULLSCREEN_MODE         , ConfigFlags_FLAG_FULLSCREEN_MODE),
+    (RESIZABLE               , ConfigFlags_FLAG_WINDOW_RESIZABLE),
+    (UNDECORATED             , ConfigFlags_FLAG_WINDOW_UNDECORATED),
+    (HIDDEN                  , ConfigFlags_FLAG_WINDOW_HIDDEN),
+    (MINIMIZED               , ConfigFlags_FLAG_WINDOW_MINIMIZED),
+    (MAXIMIZED               , ConfigFlags_FLAG_WINDOW_MAXIMIZED),
+    (UNFOCUSED               , ConfigFlags_FLAG_WINDOW_UNFOCUSED),
+    (TOPMOST                 , ConfigFlags_FLAG_WINDOW_TOPMOST),
+    (ALWAYS_RUN              , ConfigFlags_FLAG_WINDOW_ALWAYS_RUN),
+    (TRANSPARENT             , ConfigFlags_FLAG_WINDOW_TRANSPARENT),
+    (HIGHDPI                 , ConfigFlags_FLAG_WINDOW_HIGHDPI),
+    (MOUSE_PASSTHROUGH       , ConfigFlags_FLAG_WINDOW_MOUSE_PASSTHROUGH),
+    (BORDERLESS_WINDOWED_MODE, ConfigFlags_FLAG_BORDERLESS_WINDOWED_MODE),
+    (MSAA_4X                 , ConfigFlags_FLAG_MSAA_4X_HINT),
+    (INTERLACED_HINT         , ConfigFlags_FLAG_INTERLACED_HINT),
+];
 
 pub struct WindowBuilder<'s> {
     width: usize,
@@ -12,21 +37,32 @@ pub struct WindowBuilder<'s> {
     title: &'s CStr,
     icon: Option<&'s image::Image>,
     exit_key: Option<Key>,
-    #[cfg(target_os = "windows")]
-    config_flags: i32,
-    #[cfg(not(target_os = "windows"))]
-    config_flags: u32,
+    monitor: Option<Monitor>,
+    config_flags: WindowFlags,
 }
 
-#[allow(dead_code)]
 pub struct Window {
-    width: usize,
-    height: usize,
-    title: String,
-    #[cfg(target_os = "windows")]
-    config_flags: Cell<i32>,
-    #[cfg(not(target_os = "windows"))]
-    config_flags: Cell<u32>,
+    context: ContextToken,
+    focused: bool,
+    _marker: PhantomData<*mut u8>, // to make Window !Sync and !Send
+}
+
+// raylib supports only one window (and OpenGL context) at once
+static WINDOW_ALIVE: AtomicBool = AtomicBool::new(false);
+// Incremented whenever a new window is built
+static WINDOW_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Identifies the raylib context in which a GPU resource was created.
+///
+/// GPU resources should keep this and check `is_alive` before unloading themselves,
+/// because the resources are already gone with the context once the window is closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ContextToken(usize);
+
+impl ContextToken {
+    pub(crate) fn is_alive(self) -> bool {
+        WINDOW_ALIVE.load(Ordering::Acquire) && WINDOW_GENERATION.load(Ordering::Acquire) == self.0
+    }
 }
 
 impl<'s> WindowBuilder<'s> {
@@ -37,25 +73,38 @@ impl<'s> WindowBuilder<'s> {
             title,
             icon: None,
             exit_key: None,
-            config_flags: 0,
+            monitor: None,
+            config_flags: WindowFlags::empty(),
         }
     }
 
     /// Initialize Window and OpenGL context with given configs
+    /// Fails with `WindowAlreadyExistsErr` if another window is still alive
     pub fn build(&self) -> error::Result<Window> {
+        if WINDOW_ALIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(DiotekoErr::WindowAlreadyExistsErr);
+        }
+
         // SAFETY: ffi
         unsafe {
             // Initialize Window
             ffi::InitWindow(self.width as i32, self.height as i32, self.title.as_ptr());
 
+            // Moves the window before setting the state so that fullscreen mode
+            // uses the requested monitor
+            if let Some(monitor) = self.monitor {
+                ffi::SetWindowMonitor(monitor.index());
+            }
+
             // Sets Window State
-            #[cfg(target_os = "windows")]
-            ffi::SetWindowState(self.config_flags as u32);
-            #[cfg(not(target_os = "windows"))]
-            ffi::SetWindowState(self.config_flags);
+            ffi::SetWindowState(self.config_flags.bits());
 
             // Check the window is well-initialized
             if !to_bool!(ffi::IsWindowReady()) {
+                WINDOW_ALIVE.store(false, Ordering::Release);
                 return Err(DiotekoErr::WindowInitFailedErr);
             }
 
@@ -71,11 +120,13 @@ impl<'s> WindowBuilder<'s> {
             }
         }
 
+        let generation = WINDOW_GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+
         Ok(Window {
-            width: self.width,
-            height: self.height,
-            title: self.title.to_string_lossy().into_owned(),
-            config_flags: Cell::new(self.config_flags),
+            context: ContextToken(generation),
+            // SAFETY: ffi
+            focused: unsafe { to_bool!(ffi::IsWindowFocused()) },
+            _marker: PhantomData,
         })
     }
 
@@ -91,100 +142,196 @@ impl<'s> WindowBuilder<'s> {
         self
     }
 
+    /// Open the window on the given monitor
+    #[inline]
+    pub fn on_monitor(mut self, monitor: Monitor) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Enable every given config flag
+    #[inline]
+    pub fn with_flags(mut self, flags: WindowFlags) -> Self {
+        self.config_flags |= flags;
+        self
+    }
+
     #[inline]
     pub fn with_vsync_hint(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_VSYNC_HINT;
+        self.config_flags |= WindowFlags::VSYNC_HINT;
         self
     }
 
     #[inline]
     pub fn with_fullscreen(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_FULLSCREEN_MODE;
+        self.config_flags |= WindowFlags::FULLSCREEN_MODE;
         self
     }
 
     #[inline]
     pub fn with_resizable(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_RESIZABLE;
+        self.config_flags |= WindowFlags::RESIZABLE;
         self
     }
 
     #[inline]
     pub fn with_undecorated(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_UNDECORATED;
+        self.config_flags |= WindowFlags::UNDECORATED;
         self
     }
 
     #[inline]
     pub fn with_hidden(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_HIDDEN;
+        self.config_flags |= WindowFlags::HIDDEN;
         self
     }
 
     #[inline]
     pub fn with_minimized(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_MINIMIZED;
+        self.config_flags |= WindowFlags::MINIMIZED;
         self
     }
 
     #[inline]
     pub fn with_maximized(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_MAXIMIZED;
+        self.config_flags |= WindowFlags::MAXIMIZED;
         self
     }
 
     #[inline]
     pub fn with_unfocused(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_UNFOCUSED;
+        self.config_flags |= WindowFlags::UNFOCUSED;
         self
     }
 
     #[inline]
     pub fn with_topmost(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_TOPMOST;
+        self.config_flags |= WindowFlags::TOPMOST;
         self
     }
 
     #[inline]
     pub fn with_always_run(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_ALWAYS_RUN;
+        self.config_flags |= WindowFlags::ALWAYS_RUN;
         self
     }
 
     #[inline]
     pub fn with_transparent(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_TRANSPARENT;
+        self.config_flags |= WindowFlags::TRANSPARENT;
         self
     }
 
     #[inline]
     pub fn with_high_dpi(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_WINDOW_HIGHDPI;
+        self.config_flags |= WindowFlags::HIGHDPI;
         self
     }
 
     #[inline]
     pub fn with_msaa_4x(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_MSAA_4X_HINT;
+        self.config_flags |= WindowFlags::MSAA_4X;
         self
     }
 
     #[inline]
     pub fn with_interlaced_hint(mut self) -> Self {
-        self.config_flags |= ffi::ConfigFlags_FLAG_INTERLACED_HINT;
+        self.config_flags |= WindowFlags::INTERLACED_HINT;
         self
     }
 }
 
 impl Window {
+    /// Setup canvas (framebuffer) to start drawing.
+    /// The frame ends when the returned `Painter` is dropped.
     #[inline]
-    pub fn get_width(&self) -> usize {
-        self.width
+    pub fn begin_drawing(&mut self) -> Painter<'_> {
+        Painter::new(self)
+    }
+
+    /// Take every event of the current frame.
+    /// This should be called once per frame, before drawing.
+    pub fn events(&mut self) -> std::vec::IntoIter<Event> {
+        event::poll(&mut self.focused).into_iter()
     }
 
     #[inline]
+    pub(crate) fn context(&self) -> ContextToken {
+        self.context
+    }
+
+    /// Get the current screen width
+    pub fn get_width(&self) -> usize {
+        // SAFETY: ffi
+        unsafe { ffi::GetScreenWidth() as usize }
+    }
+
+    /// Get the current screen height
     pub fn get_height(&self) -> usize {
-        self.height
+        // SAFETY: ffi
+        unsafe { ffi::GetScreenHeight() as usize }
+    }
+
+    /// Get the current render width (it considers HiDPI)
+    pub fn get_render_width(&self) -> usize {
+        // SAFETY: ffi
+        unsafe { ffi::GetRenderWidth() as usize }
+    }
+
+    /// Get the current render height (it considers HiDPI)
+    pub fn get_render_height(&self) -> usize {
+        // SAFETY: ffi
+        unsafe { ffi::GetRenderHeight() as usize }
+    }
+
+    pub fn get_position(&self) -> Vector2 {
+        // SAFETY: ffi
+        unsafe { ffi::GetWindowPosition() }.into()
+    }
+
+    /// Get the scale factor of the window for HiDPI monitors
+    pub fn get_scale_dpi(&self) -> Vector2 {
+        // SAFETY: ffi
+        unsafe { ffi::GetWindowScaleDPI() }.into()
+    }
+
+    /// Get the monitor where the window is placed
+    #[inline]
+    pub fn get_monitor(&self) -> Monitor {
+        Monitor::current()
+    }
+
+    /// If enabled, the window waits for input events on every end of a frame instead
+    /// of polling them, so the application sleeps until there is something to redraw.
+    pub fn set_event_waiting(&self, enabled: bool) {
+        // SAFETY: ffi
+        unsafe {
+            if enabled {
+                ffi::EnableEventWaiting()
+            } else {
+                ffi::DisableEventWaiting()
+            }
+        }
+    }
+
+    /// Swap back buffer with front buffer to present the drawn frame.
+    ///
+    /// With the `custom-frame-control` feature, dropping `Painter` does not present
+    /// the frame anymore, so this must be called after every frame.
+    #[cfg(feature = "custom-frame-control")]
+    pub fn swap_screen_buffer(&mut self) {
+        // SAFETY: ffi
+        unsafe { ffi::SwapScreenBuffer() }
+    }
+
+    /// Register all input events.
+    ///
+    /// With the `custom-frame-control` feature, dropping `Painter` does not poll
+    /// events anymore, so this must be called once per frame.
+    #[cfg(feature = "custom-frame-control")]
+    pub fn poll_input_events(&mut self) {
+        // SAFETY: ffi
+        unsafe { ffi::PollInputEvents() }
     }
 
     pub fn should_close(&self) -> bool {
@@ -227,65 +374,112 @@ impl Window {
         unsafe { to_bool!(ffi::IsWindowResized()) }
     }
 
+    /// Check whether every given config flag is currently enabled
+    pub fn is_state(&self, flags: WindowFlags) -> bool {
+        // SAFETY: ffi
+        unsafe { to_bool!(ffi::IsWindowState(flags.bits())) }
+    }
+
+    /// Get every config flag which is currently enabled
+    pub fn state(&self) -> WindowFlags {
+        WindowFlags::all()
+            .iter()
+            .filter(|&flag| self.is_state(flag))
+            .collect()
+    }
+
+    /// Enable the given config flags (only the supported ones)
+    pub fn set_state(&self, flags: WindowFlags) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowState(flags.bits()) }
+    }
+
+    /// Disable the given config flags
+    pub fn clear_state(&self, flags: WindowFlags) {
+        // SAFETY: ffi
+        unsafe { ffi::ClearWindowState(flags.bits()) }
+    }
+
+    pub fn toggle_fullscreen(&self) {
+        // SAFETY: ffi
+        unsafe { ffi::ToggleFullscreen() }
+    }
+
+    #[deprecated(note = "use `toggle_fullscreen` instead")]
+    #[inline]
     pub fn toogle_fullscreen(&self) {
-        // Updates config_flag
-        let mut config_flags = self.config_flags.get();
-        config_flags |= ffi::ConfigFlags_FLAG_FULLSCREEN_MODE;
-        self.config_flags.set(config_flags);
+        self.toggle_fullscreen();
+    }
 
+    pub fn toggle_borderless_windowed(&self) {
         // SAFETY: ffi
-        unsafe {
-            #[cfg(target_os = "windows")]
-            ffi::SetWindowState(config_flags as u32);
-            #[cfg(not(target_os = "windows"))]
-            ffi::SetWindowState(config_flags);
-        }
+        unsafe { ffi::ToggleBorderlessWindowed() }
     }
 
     pub fn maximize_window(&self) {
-        // Updates config_flag
-        let mut config_flags = self.config_flags.get();
-        config_flags |= ffi::ConfigFlags_FLAG_WINDOW_MAXIMIZED;
-        self.config_flags.set(config_flags);
-
         // SAFETY: ffi
-        unsafe {
-            #[cfg(target_os = "windows")]
-            ffi::SetWindowState(config_flags as u32);
-            #[cfg(not(target_os = "windows"))]
-            ffi::SetWindowState(config_flags);
-        }
+        unsafe { ffi::MaximizeWindow() }
     }
 
     pub fn minimize_window(&self) {
-        // Updates config_flag
-        let mut config_flags = self.config_flags.get();
-        config_flags |= ffi::ConfigFlags_FLAG_WINDOW_MINIMIZED;
-        self.config_flags.set(config_flags);
-
         // SAFETY: ffi
-        unsafe {
-            #[cfg(target_os = "windows")]
-            ffi::SetWindowState(config_flags as u32);
-            #[cfg(not(target_os = "windows"))]
-            ffi::SetWindowState(config_flags);
-        }
+        unsafe { ffi::MinimizeWindow() }
     }
 
     pub fn restore_window(&self) {
-        // Updates config_flag
-        let mut config_flags = self.config_flags.get();
-        config_flags &=
-            !(ffi::ConfigFlags_FLAG_WINDOW_MAXIMIZED | ffi::ConfigFlags_FLAG_WINDOW_MINIMIZED);
-        self.config_flags.set(config_flags);
+        // SAFETY: ffi
+        unsafe { ffi::RestoreWindow() }
+    }
 
+    pub fn set_title(&self, title: &CStr) {
         // SAFETY: ffi
-        unsafe {
-            #[cfg(target_os = "windows")]
-            ffi::SetWindowState(config_flags as u32);
-            #[cfg(not(target_os = "windows"))]
-            ffi::SetWindowState(config_flags);
-        }
+        unsafe { ffi::SetWindowTitle(title.as_ptr()) }
+    }
+
+    pub fn set_icon(&self, icon: &image::Image) {
+        // SAFETY: ffi
+        // SAFETY: icon.take_raw() makes a temporary data
+        unsafe { ffi::SetWindowIcon(icon.take_raw()) }
+    }
+
+    pub fn set_position(&self, x: i32, y: i32) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowPosition(x, y) }
+    }
+
+    pub fn set_size(&self, width: usize, height: usize) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowSize(width as i32, height as i32) }
+    }
+
+    /// Set the minimum dimensions of the window (only for resizable windows)
+    pub fn set_min_size(&self, width: usize, height: usize) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowMinSize(width as i32, height as i32) }
+    }
+
+    /// Set the maximum dimensions of the window (only for resizable windows)
+    pub fn set_max_size(&self, width: usize, height: usize) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowMaxSize(width as i32, height as i32) }
+    }
+
+    /// Set the opacity of the window. `opacity` is clamped to the range [0.0, 1.0]
+    pub fn set_opacity(&self, opacity: f32) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowOpacity(opacity.clamp(0.0, 1.0)) }
+    }
+
+    /// Move the window to the given monitor. If the window is in fullscreen mode,
+    /// it becomes fullscreen on that monitor
+    pub fn move_to_monitor(&self, monitor: Monitor) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowMonitor(monitor.index()) }
+    }
+
+    pub fn set_focused(&self) {
+        // SAFETY: ffi
+        unsafe { ffi::SetWindowFocused() }
     }
 }
 
@@ -295,5 +489,7 @@ impl Drop for Window {
         unsafe {
             ffi::CloseWindow();
         }
+
+        WINDOW_ALIVE.store(false, Ordering::Release);
     }
 }
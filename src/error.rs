@@ -1,18 +1,129 @@
+use std::error::Error;
+use std::ffi::{CStr, NulError};
 use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DiotekoErr {
     WindowInitFailedErr,
-    LoadImageFailedErr,
+    WindowAlreadyExistsErr,
+    LoadImageFailedErr {
+        path: Option<PathBuf>,
+        reason: String,
+    },
+    LoadTextureFailedErr {
+        path: Option<PathBuf>,
+        reason: String,
+    },
+    LoadRenderTextureFailedErr {
+        width: i32,
+        height: i32,
+    },
+    LoadFileFailedErr {
+        path: PathBuf,
+        reason: String,
+    },
+    SaveFileFailedErr {
+        path: PathBuf,
+        reason: String,
+    },
+    /// raylib could not decode the data, because its build does not support the
+    /// format or the data is broken
+    UnsupportedFormatErr {
+        path: Option<PathBuf>,
+        format: String,
+    },
+    AudioDeviceNotReadyErr,
+    InvalidGamepadMappingsErr,
+    /// A string passed to raylib contains a nul character at the given byte position
+    InteriorNulErr(usize),
+    UnknownEnumValue(i32),
+    ParseEnumErr(String),
+    ParseBindingErr(String),
+    ParseColorErr(String),
+}
+
+impl DiotekoErr {
+    pub(crate) fn load_file(path: impl AsRef<Path>, reason: impl Display) -> Self {
+        Self::LoadFileFailedErr {
+            path: path.as_ref().to_path_buf(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub(crate) fn save_file(path: impl AsRef<Path>, reason: impl Display) -> Self {
+        Self::SaveFileFailedErr {
+            path: path.as_ref().to_path_buf(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+// raylib takes the file names as C strings
+pub(crate) fn path_of(filename: &CStr) -> PathBuf {
+    PathBuf::from(filename.to_string_lossy().into_owned())
+}
+
+fn write_path(f: &mut fmt::Formatter<'_>, path: &Option<PathBuf>) -> fmt::Result {
+    match path {
+        Some(path) => write!(f, " from {}", path.display()),
+        None => Ok(()),
+    }
 }
 
 impl Display for DiotekoErr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::WindowInitFailedErr => write!(f, "Failed to initialize window"),
-            Self::LoadImageFailedErr => write!(f, "Failed to load an image"),
+            Self::WindowAlreadyExistsErr => write!(f, "Only one window can exist at once"),
+            Self::LoadImageFailedErr { path, reason } => {
+                write!(f, "Failed to load an image")?;
+                write_path(f, path)?;
+                write!(f, ": {reason}")
+            }
+            Self::LoadTextureFailedErr { path, reason } => {
+                write!(f, "Failed to load a texture")?;
+                write_path(f, path)?;
+                write!(f, ": {reason}")
+            }
+            Self::LoadRenderTextureFailedErr { width, height } => {
+                write!(
+                    f,
+                    "Failed to load a render texture of size {width}x{height}"
+                )
+            }
+            Self::LoadFileFailedErr { path, reason } => {
+                write!(f, "Failed to load {}: {reason}", path.display())
+            }
+            Self::SaveFileFailedErr { path, reason } => {
+                write!(f, "Failed to save {}: {reason}", path.display())
+            }
+            Self::UnsupportedFormatErr { path, format } => {
+                write!(f, "Unsupported file format `{format}`")?;
+                match path {
+                    Some(path) => write!(f, " of {}", path.display()),
+                    None => Ok(()),
+                }
+            }
+            Self::AudioDeviceNotReadyErr => write!(f, "The audio device is not ready"),
+            Self::InvalidGamepadMappingsErr => write!(f, "Failed to update gamepad mappings"),
+            Self::InteriorNulErr(pos) => {
+                write!(f, "String contains a nul character at position {pos}")
+            }
+            Self::UnknownEnumValue(value) => write!(f, "Unknown enum value {value}"),
+            Self::ParseEnumErr(name) => write!(f, "Unknown enum name `{name}`"),
+            Self::ParseBindingErr(binding) => write!(f, "Invalid input binding `{binding}`"),
+            Self::ParseColorErr(color) => write!(f, "Invalid color `{color}`"),
         }
     }
 }
 
+impl Error for DiotekoErr {}
+
+impl From<NulError> for DiotekoErr {
+    fn from(err: NulError) -> Self {
+        Self::InteriorNulErr(err.nul_position())
+    }
+}
+
 pub type Result<T> = std::result::Result<T, DiotekoErr>;
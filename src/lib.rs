@@ -1,3 +1,4 @@
+pub mod app;
 pub mod core;
 pub mod error;
 pub mod prelude;
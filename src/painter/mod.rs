@@ -6,13 +6,19 @@ use std::marker::PhantomData;
 
 use crate::core::color::Color;
 use crate::core::linalg::Vector2;
+use crate::core::window::Window;
 use crate::ffi;
 
-pub struct Painter(PhantomData<*mut u8>); // to make Painter !Sync and !Send
+/// A drawing handle for one frame
+///
+/// It can only be obtained with `Window::begin_drawing`, which mutably borrows the
+/// window. Hence there is at most one frame in flight and a `Painter` can never
+/// outlive the window.
+pub struct Painter<'w>(PhantomData<&'w mut Window>); // Window is !Sync and !Send
 
 /// basic drawings
-impl Painter {
-    pub fn new() -> Self {
+impl<'w> Painter<'w> {
+    pub(crate) fn new(_window: &'w mut Window) -> Self {
         // SAFETY: ffi
         unsafe {
             ffi::BeginDrawing();
@@ -33,7 +39,7 @@ impl Painter {
     }
 }
 
-impl Drop for Painter {
+impl Drop for Painter<'_> {
     fn drop(&mut self) {
         // SAFETY: ffi
         unsafe {
@@ -1,14 +1,61 @@
 pub mod font;
 
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 
 use super::Painter;
 use crate::core::color::Color;
+use crate::core::text_input::TextInput;
 use crate::ffi;
 
-impl Painter {
+impl Painter<'_> {
     pub fn draw_text(&self, text: &CStr, pos_x: i32, pos_y: i32, font_size: i32, color: Color) {
         // SAFETY: ffi
         unsafe { ffi::DrawText(text.as_ptr(), pos_x, pos_y, font_size, color.into()) }
     }
+
+    /// Draw the text of the input with its selection and cursor using the default font
+    pub fn draw_text_input(
+        &self,
+        input: &TextInput,
+        pos_x: i32,
+        pos_y: i32,
+        font_size: i32,
+        color: Color,
+    ) {
+        let text = input.text();
+        let width_of = |end: usize| measure_text(&text[..end], font_size);
+
+        if let Some(selection) = input.selection() {
+            let start = width_of(selection.start);
+            let end = width_of(selection.end);
+            // SAFETY: ffi
+            unsafe {
+                ffi::DrawRectangle(
+                    pos_x + start,
+                    pos_y,
+                    end - start,
+                    font_size,
+                    color.fade(0.3).into(),
+                )
+            }
+        }
+
+        // TextInput never contains nul characters
+        let c_text = CString::new(text).unwrap_or_default();
+        self.draw_text(&c_text, pos_x, pos_y, font_size, color);
+
+        let cursor_x = pos_x + width_of(input.cursor());
+        // SAFETY: ffi
+        unsafe { ffi::DrawLine(cursor_x, pos_y, cursor_x, pos_y + font_size, color.into()) }
+    }
+}
+
+fn measure_text(text: &str, font_size: i32) -> i32 {
+    if text.is_empty() {
+        return 0;
+    }
+
+    let c_text = CString::new(text).unwrap_or_default();
+    // SAFETY: ffi
+    unsafe { ffi::MeasureText(c_text.as_ptr(), font_size) }
 }
@@ -1,5 +1,7 @@
 use std::ffi::CStr;
 
+use crate::core::window::Window;
+use crate::error::{self, DiotekoErr};
 use crate::ffi;
 use crate::painter::textures::texture::Texture;
 
@@ -8,13 +10,45 @@ pub struct Image {
     image: ffi::Image,
 }
 
+// Checks that the file exists, so that a failure tells why
+fn check_file_exists(filename: &CStr) -> error::Result<()> {
+    // SAFETY: ffi
+    if unsafe { crate::to_bool!(ffi::FileExists(filename.as_ptr())) } {
+        Ok(())
+    } else {
+        Err(DiotekoErr::load_file(
+            error::path_of(filename),
+            "the file does not exist",
+        ))
+    }
+}
+
+// The formats depend on how raylib was built, so raylib failing to decode an existing
+// file is the only sign of an unsupported format. A broken file is reported the same.
+fn unsupported_file(filename: &CStr) -> DiotekoErr {
+    let path = error::path_of(filename);
+    let format = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    DiotekoErr::UnsupportedFormatErr {
+        path: Some(path),
+        format,
+    }
+}
+
 impl Image {
-    pub fn load_image(filename: &CStr) -> Self {
+    /// Load an image file. The supported formats depend on the raylib build.
+    pub fn load_image(filename: &CStr) -> error::Result<Self> {
+        check_file_exists(filename)?;
+
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImage makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
-        unsafe { Image::from_raw(ffi::LoadImage(filename.as_ptr())) }
+        let image = unsafe { Image::from_raw(ffi::LoadImage(filename.as_ptr())) };
+        image.ready_or(|| unsupported_file(filename))
     }
 
     pub fn load_image_raw(
@@ -23,12 +57,14 @@ impl Image {
         height: i32,
         format: i32,
         header_size: i32,
-    ) -> Self {
+    ) -> error::Result<Self> {
+        check_file_exists(filename)?;
+
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImageRaw makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
-        unsafe {
+        let image = unsafe {
             Image::from_raw(ffi::LoadImageRaw(
                 filename.as_ptr(),
                 width,
@@ -36,48 +72,82 @@ impl Image {
                 format,
                 header_size,
             ))
-        }
+        };
+        image.ready_or(|| DiotekoErr::LoadImageFailedErr {
+            path: Some(error::path_of(filename)),
+            reason: "could not read the raw image data".to_string(),
+        })
     }
 
-    pub fn load_image_from_memory(filename: &CStr, file_data: &[u8]) -> Self {
+    /// Load an image from the content of a file. `file_type` is the extension of the
+    /// file such as `.png`.
+    pub fn load_image_from_memory(file_type: &CStr, file_data: &[u8]) -> error::Result<Self> {
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImageFromMemory makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
-        unsafe {
+        let image = unsafe {
             Image::from_raw(ffi::LoadImageFromMemory(
-                filename.as_ptr(),
+                file_type.as_ptr(),
                 file_data.as_ptr(),
                 file_data.len() as i32,
             ))
-        }
+        };
+        image.ready_or(|| DiotekoErr::UnsupportedFormatErr {
+            path: None,
+            format: file_type
+                .to_string_lossy()
+                .trim_start_matches('.')
+                .to_string(),
+        })
     }
 
-    pub fn load_image_from_texture(texture: Texture) -> Self {
+    pub fn load_image_from_texture(texture: Texture) -> error::Result<Self> {
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImageFromTexture makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
         // SAFETY: since we do not convert back to the Texture, ignoring the second
         // value of the into_raw is fine
-        unsafe { Image::from_raw(ffi::LoadImageFromTexture(texture.into_raw().0)) }
+        let image = unsafe { Image::from_raw(ffi::LoadImageFromTexture(texture.into_raw().0)) };
+        image.ready_or(|| DiotekoErr::LoadImageFailedErr {
+            path: None,
+            reason: "could not read the texture from the GPU".to_string(),
+        })
     }
 
-    pub fn load_image_from_screen() -> Self {
+    pub fn load_image_from_screen(_window: &Window) -> error::Result<Self> {
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImageFromScreen makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
-        unsafe { Image::from_raw(ffi::LoadImageFromScreen()) }
+        let image = unsafe { Image::from_raw(ffi::LoadImageFromScreen()) };
+        image.ready_or(|| DiotekoErr::LoadImageFailedErr {
+            path: None,
+            reason: "could not read the screen buffer".to_string(),
+        })
     }
 
     /// This function is unsafe because it takes a raw pointer as its parameter
-    pub unsafe fn load_image_anim(filename: &CStr, frames: *mut i32) -> Self {
+    pub unsafe fn load_image_anim(filename: &CStr, frames: *mut i32) -> error::Result<Self> {
+        check_file_exists(filename)?;
+
         // SAFETY: ffi
         // SAFETY: Since ffi::LoadImageAnim makes a temporary ffi::Image and
         // ffi::Image has no destructor, making Image with from_raw satisfies
         // all conditions of safety.
-        Image::from_raw(ffi::LoadImageAnim(filename.as_ptr(), frames))
+        let image = Image::from_raw(ffi::LoadImageAnim(filename.as_ptr(), frames));
+        image.ready_or(|| unsupported_file(filename))
+    }
+
+    // An image which is not ready has no data, so dropping it is fine
+    fn ready_or(self, err: impl FnOnce() -> DiotekoErr) -> error::Result<Self> {
+        // SAFETY: ffi
+        if unsafe { crate::to_bool!(ffi::IsImageReady(self.image)) } {
+            Ok(self)
+        } else {
+            Err(err())
+        }
     }
 
     /// Convert ffi::Image structure into Rust's one.
@@ -1,19 +1,23 @@
 use std::cell::Cell;
 use std::ptr::NonNull;
 
+use crate::core::window::{ContextToken, Window};
+use crate::error::{self, DiotekoErr};
 use crate::ffi;
 
 // strong-weak tracker
 pub(crate) struct RenderTextureRc {
     strong: Cell<usize>,
     weak: Cell<usize>,
+    context: ContextToken,
 }
 
 impl RenderTextureRc {
-    fn new() -> Self {
+    fn new(context: ContextToken) -> Self {
         Self {
             strong: Cell::new(1),
             weak: Cell::new(1),
+            context,
         }
     }
 }
@@ -21,6 +25,7 @@ impl RenderTextureRc {
 trait RenderTextureRcControll {
     fn get_strong(&self) -> &Cell<usize>;
     fn get_weak(&self) -> &Cell<usize>;
+    fn context(&self) -> ContextToken;
 
     #[inline]
     fn strong(&self) -> usize {
@@ -62,12 +67,30 @@ pub type RenderTexture2D = RenderTexture;
 
 // Implementation of Texture type
 impl RenderTexture {
-    pub fn load(width: i32, height: i32) -> Self {
+    /// Load a render texture into the GPU of the given window
+    pub fn load(window: &Window, width: i32, height: i32) -> error::Result<Self> {
         // SAFETY: ffi
-        Self {
-            render_texture: unsafe { ffi::LoadRenderTexture(width, height) },
-            rc_count: Box::leak(Box::new(RenderTextureRc::new())).into(),
+        let render_texture = unsafe { ffi::LoadRenderTexture(width, height) };
+
+        // SAFETY: ffi
+        if !unsafe { crate::to_bool!(ffi::IsRenderTextureReady(render_texture)) } {
+            // SAFETY: ffi
+            // SAFETY: the framebuffer may have been made even though it is incomplete
+            unsafe { ffi::UnloadRenderTexture(render_texture) }
+            return Err(DiotekoErr::LoadRenderTextureFailedErr { width, height });
         }
+
+        Ok(Self {
+            render_texture,
+            rc_count: Box::leak(Box::new(RenderTextureRc::new(window.context()))).into(),
+        })
+    }
+
+    /// Check whether the window which loaded the render texture is still open.
+    /// The render texture has gone with the window otherwise.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.context().is_alive()
     }
 
     pub fn downgrade(val: &Self) -> WeakRenderTexture {
@@ -108,8 +131,11 @@ impl Drop for RenderTexture {
 
         if self.strong() == 0 {
             // SAFETY: since we checked that there is no other owned inner render texture,
-            // dropping it is fine
-            unsafe { ffi::UnloadRenderTexture(self.render_texture) }
+            // dropping it is fine. If the window was already closed, the render texture has
+            // gone with the OpenGL context, so it must not be unloaded again.
+            if self.context().is_alive() {
+                unsafe { ffi::UnloadRenderTexture(self.render_texture) }
+            }
 
             self.dec_weak();
             if self.weak() == 0 {
@@ -130,7 +156,7 @@ pub type WeakRenderTexture2D = WeakRenderTexture;
 
 impl WeakRenderTexture {
     pub fn upgrade(&self) -> Option<RenderTexture> {
-        if self.strong() != 0 {
+        if self.strong() != 0 && self.context().is_alive() {
             Some(RenderTexture {
                 render_texture: self.render_texture,
                 rc_count: self.rc_count,
@@ -196,6 +222,11 @@ impl RenderTextureRcControll for RenderTexture {
         // SAFETY: reference is alive as long as the texture is alive
         unsafe { &self.rc_count.as_ref().weak }
     }
+
+    fn context(&self) -> ContextToken {
+        // SAFETY: reference is alive as long as the render_texture is alive
+        unsafe { self.rc_count.as_ref().context }
+    }
 }
 
 impl RenderTextureRcControll for WeakRenderTexture {
@@ -208,4 +239,9 @@ impl RenderTextureRcControll for WeakRenderTexture {
         // SAFETY: reference is alive as long as the weak_texture is alive
         unsafe { &self.rc_count.as_ref().weak }
     }
+
+    fn context(&self) -> ContextToken {
+        // SAFETY: reference is alive as long as the weak_render_texture is alive
+        unsafe { self.rc_count.as_ref().context }
+    }
 }
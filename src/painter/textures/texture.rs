@@ -6,6 +6,8 @@ use crate::core::color::Color;
 use crate::core::linalg::Vector2;
 use crate::core::npatchinfo::NPatchInfo;
 use crate::core::rectangle::Rectangle;
+use crate::core::window::{ContextToken, Window};
+use crate::error::{self, DiotekoErr};
 use crate::ffi;
 use crate::painter::textures::image::Image;
 use crate::painter::Painter;
@@ -14,13 +16,15 @@ use crate::painter::Painter;
 pub(crate) struct TextureRc {
     strong: Cell<usize>,
     weak: Cell<usize>,
+    context: ContextToken,
 }
 
 impl TextureRc {
-    fn new() -> Self {
+    fn new(context: ContextToken) -> Self {
         Self {
             strong: Cell::new(1),
             weak: Cell::new(1),
+            context,
         }
     }
 }
@@ -28,6 +32,7 @@ impl TextureRc {
 trait TextureRcControll {
     fn get_strong(&self) -> &Cell<usize>;
     fn get_weak(&self) -> &Cell<usize>;
+    fn context(&self) -> ContextToken;
 
     #[inline]
     fn strong(&self) -> usize {
@@ -69,21 +74,37 @@ pub type Texture2D = Texture;
 
 // Implementation of Texture type
 impl Texture {
-    pub fn load(filename: &CStr) -> Self {
-        // SAFETY: ffi
-        Self {
-            texture: unsafe { ffi::LoadTexture(filename.as_ptr()) },
-            rc_count: Box::leak(Box::new(TextureRc::new())).into(),
-        }
+    /// Load a texture into the GPU of the given window
+    pub fn load(window: &Window, filename: &CStr) -> error::Result<Self> {
+        // Same as LoadTexture, but tells a decoding failure from an upload failure
+        let image = Image::load_image(filename)?;
+        Self::from_image(window, &image).map_err(|_| DiotekoErr::LoadTextureFailedErr {
+            path: Some(error::path_of(filename)),
+            reason: "could not upload the image to the GPU".to_string(),
+        })
     }
 
-    pub fn from_image(image: &Image) -> Self {
+    pub fn from_image(window: &Window, image: &Image) -> error::Result<Self> {
         // SAFETY: ffi
         // SAFETY: image.into_raw() is temporary, it is also safe
-        Self {
-            texture: unsafe { ffi::LoadTextureFromImage(image.take_raw()) },
-            rc_count: Box::leak(Box::new(TextureRc::new())).into(),
+        let texture = unsafe { ffi::LoadTextureFromImage(image.take_raw()) };
+        Self::new(window, texture).ok_or_else(|| DiotekoErr::LoadTextureFailedErr {
+            path: None,
+            reason: "could not upload the image to the GPU".to_string(),
+        })
+    }
+
+    // A texture which is not ready has not been uploaded, so nothing is leaked
+    fn new(window: &Window, texture: ffi::Texture) -> Option<Self> {
+        // SAFETY: ffi
+        if !unsafe { crate::to_bool!(ffi::IsTextureReady(texture)) } {
+            return None;
         }
+
+        Some(Self {
+            texture,
+            rc_count: Box::leak(Box::new(TextureRc::new(window.context()))).into(),
+        })
     }
 
     pub fn downgrade(val: &Self) -> WeakTexture {
@@ -95,6 +116,13 @@ impl Texture {
         }
     }
 
+    /// Check whether the window which loaded the texture is still open.
+    /// The texture has gone with the window otherwise, so drawing it does nothing.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.context().is_alive()
+    }
+
     #[inline]
     pub fn id(&self) -> u32 {
         self.texture.id
@@ -143,8 +171,11 @@ impl Drop for Texture {
 
         if self.strong() == 0 {
             // SAFETY: since we checked that there is no other owned inner texture,
-            // dropping it is fine
-            unsafe { ffi::UnloadTexture(self.texture) }
+            // dropping it is fine. If the window was already closed, the texture has
+            // gone with the OpenGL context, so it must not be unloaded again.
+            if self.context().is_alive() {
+                unsafe { ffi::UnloadTexture(self.texture) }
+            }
 
             self.dec_weak();
             if self.weak() == 0 {
@@ -165,7 +196,7 @@ pub type WeakTexture2D = WeakTexture;
 
 impl WeakTexture {
     pub fn upgrade(&self) -> Option<Texture> {
-        if self.strong() != 0 {
+        if self.strong() != 0 && self.context().is_alive() {
             Some(Texture {
                 texture: self.texture,
                 rc_count: self.rc_count,
@@ -225,6 +256,11 @@ impl TextureRcControll for Texture {
         // SAFETY: reference is alive as long as the texture is alive
         unsafe { &self.rc_count.as_ref().weak }
     }
+
+    fn context(&self) -> ContextToken {
+        // SAFETY: reference is alive as long as the texture is alive
+        unsafe { self.rc_count.as_ref().context }
+    }
 }
 
 impl TextureRcControll for WeakTexture {
@@ -237,16 +273,32 @@ impl TextureRcControll for WeakTexture {
         // SAFETY: reference is alive as long as the weak_texture is alive
         unsafe { &self.rc_count.as_ref().weak }
     }
+
+    fn context(&self) -> ContextToken {
+        // SAFETY: reference is alive as long as the weak_texture is alive
+        unsafe { self.rc_count.as_ref().context }
+    }
 }
 
 /// drawing related to texture
-impl Painter {
+///
+/// A texture whose window has been closed is skipped, since its id may name another
+/// texture of the current window.
+impl Painter<'_> {
     pub fn draw_texture(&self, texture: Texture, pos_x: i32, pos_y: i32, tint: Color) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe { ffi::DrawTexture(texture.texture, pos_x, pos_y, tint.into()) }
     }
 
     pub fn draw_texture_v(&self, texture: Texture, position: Vector2, tint: Color) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe { ffi::DrawTextureV(texture.texture, position.into(), tint.into()) }
     }
@@ -259,6 +311,10 @@ impl Painter {
         scale: f32,
         tint: Color,
     ) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe {
             ffi::DrawTextureEx(
@@ -278,6 +334,10 @@ impl Painter {
         position: Vector2,
         tint: Color,
     ) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe { ffi::DrawTextureRec(texture.texture, source.into(), position.into(), tint.into()) }
     }
@@ -291,6 +351,10 @@ impl Painter {
         rotation: f32,
         tint: Color,
     ) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe {
             ffi::DrawTexturePro(
@@ -313,6 +377,10 @@ impl Painter {
         rotation: f32,
         tint: Color,
     ) {
+        if !texture.is_valid() {
+            return;
+        }
+
         // SAFETY: ffi
         unsafe {
             ffi::DrawTextureNPatch(
@@ -1,10 +1,23 @@
 pub use crate::core::color::*;
 pub use crate::core::window::*;
 
+pub use crate::core::monitor::{self, Monitor};
+
+pub use crate::core::action::{self, ActionMap, Binding};
+pub use crate::core::clipboard;
+pub use crate::core::combo::{self, Chord, SequenceDetector};
+pub use crate::core::event::Event;
+pub use crate::core::gamepad::{self, Gamepad, GamepadAxis, GamepadButton};
+pub use crate::core::input_state::InputState;
 pub use crate::core::keyboard::{self, Key};
-pub use crate::core::mouse::{self, MouseButton, MouseCursor};
+pub use crate::core::mouse::{self, cursor, MouseButton, MouseCursor};
+pub use crate::core::text_input::TextInput;
+pub use crate::core::touch::{self, Gesture, TouchPoint};
+pub use crate::core::trace_log::{self, TraceLogLevel};
 pub use crate::core::utility;
 
-pub use crate::core::linalg::{self, Vector2, Vector3, Vector4};
+pub use crate::core::linalg::{self, Matrix, Quaternion, Vector2, Vector3, Vector4};
 
 pub use crate::painter::{self, Painter};
+
+pub use crate::app::{self, App};
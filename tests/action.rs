@@ -0,0 +1,136 @@
+use dioteko::core::action::{ActionMap, Binding, Input};
+use dioteko::core::gamepad::{GamepadAxis, GamepadButton};
+use dioteko::core::keyboard::Key;
+use dioteko::core::mouse::MouseButton;
+use dioteko::error::DiotekoErr;
+
+const CONFIG: &str = "
+# action = binding, binding, ...
+jump   = Space, Gamepad:RightFaceDown
+move_x = D, -A, GamepadAxis:LeftX
+walk_x = 0.5*Right, -0.25 * Left
+save   = LeftControl+S
+zoom   = MouseWheel, Mouse:Middle
+none   =
+";
+
+#[test]
+fn parse_config() {
+    let map: ActionMap = CONFIG.parse().unwrap();
+
+    assert_eq!(
+        map.bindings("jump"),
+        [
+            Binding::new(Input::Keys(vec![Key::Space])),
+            Binding::new(Input::GamepadButton(GamepadButton::RightFaceDown)),
+        ]
+    );
+    assert_eq!(
+        map.bindings("move_x"),
+        [
+            Binding::new(Input::Keys(vec![Key::D])),
+            Binding::negated(Input::Keys(vec![Key::A])),
+            Binding::new(Input::GamepadAxis(GamepadAxis::LeftX)),
+        ]
+    );
+    assert_eq!(
+        map.bindings("walk_x"),
+        [
+            Binding {
+                input: Input::Keys(vec![Key::Right]),
+                scale: 0.5,
+            },
+            Binding {
+                input: Input::Keys(vec![Key::Left]),
+                scale: -0.25,
+            },
+        ]
+    );
+    assert_eq!(
+        map.bindings("save"),
+        [Binding::new(Input::Keys(vec![Key::LeftControl, Key::S]))]
+    );
+    assert_eq!(
+        map.bindings("zoom"),
+        [
+            Binding::new(Input::MouseWheel),
+            Binding::new(Input::Mouse(MouseButton::Middle)),
+        ]
+    );
+    assert!(map.bindings("none").is_empty());
+    assert_eq!(map.actions().count(), 6);
+}
+
+#[test]
+fn round_trip() {
+    let map: ActionMap = CONFIG.parse().unwrap();
+    let saved = map.to_string();
+    let reloaded: ActionMap = saved.parse().unwrap();
+
+    for action in map.actions() {
+        assert_eq!(reloaded.bindings(action), map.bindings(action), "{action}");
+    }
+    assert_eq!(reloaded.to_string(), saved);
+    assert!(saved.contains("walk_x = 0.5*Right, -0.25*Left"));
+}
+
+#[test]
+fn binding_scale() {
+    let binding = Binding {
+        input: Input::Keys(vec![Key::A]),
+        scale: 0.1,
+    };
+    assert_eq!(binding.to_string(), "0.1*A");
+    assert_eq!(binding.to_string().parse::<Binding>().unwrap(), binding);
+
+    assert_eq!(
+        Binding::negated(Input::MouseWheel).to_string(),
+        "-MouseWheel"
+    );
+    assert_eq!("-1*A".parse::<Binding>().unwrap().to_string(), "-A");
+}
+
+#[test]
+fn malformed_lines() {
+    for line in [
+        "jump Space",
+        " = Space",
+        "jump = NoSuchKey",
+        "jump = LeftControl+",
+        "jump = Mouse:NoSuchButton",
+        "jump = Keyboard:Space",
+        "jump = half*Space",
+        "jump = 0.5*",
+    ] {
+        assert!(
+            matches!(
+                line.parse::<ActionMap>(),
+                Err(DiotekoErr::ParseBindingErr(_))
+            ),
+            "{line}"
+        );
+    }
+}
+
+#[test]
+fn load_and_save() {
+    let path = std::env::temp_dir().join(format!("dioteko-action-{}.cfg", std::process::id()));
+
+    let map: ActionMap = CONFIG.parse().unwrap();
+    map.save(&path).unwrap();
+
+    let mut loaded = ActionMap::new();
+    loaded.bind("jump", Key::W);
+    loaded.bind("other", Key::O);
+    loaded.load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // The actions in the file are replaced and the others are kept
+    assert_eq!(loaded.bindings("jump"), map.bindings("jump"));
+    assert_eq!(loaded.bindings("other"), [Binding::from(Key::O)]);
+
+    assert!(matches!(
+        loaded.load(&path),
+        Err(DiotekoErr::LoadFileFailedErr { .. })
+    ));
+}
@@ -0,0 +1,68 @@
+use dioteko::app::FixedStepper;
+
+#[test]
+fn exact_multiples_of_the_step() {
+    let mut stepper = FixedStepper::new(0.25, 8);
+
+    assert_eq!(stepper.advance(0.25), 1);
+    assert_eq!(stepper.alpha(), 0.0);
+    assert_eq!(stepper.advance(0.75), 3);
+    assert_eq!(stepper.alpha(), 0.0);
+    assert_eq!(stepper.advance(0.0), 0);
+}
+
+#[test]
+fn accumulates_partial_steps() {
+    let mut stepper = FixedStepper::new(0.25, 8);
+
+    assert_eq!(stepper.advance(0.125), 0);
+    assert_eq!(stepper.alpha(), 0.5);
+    assert_eq!(stepper.advance(0.1875), 1);
+    assert_eq!(stepper.alpha(), 0.25);
+
+    // Negative and infinite frame times are ignored
+    assert_eq!(stepper.advance(-1.0), 0);
+    assert_eq!(stepper.advance(f64::INFINITY), 0);
+    assert_eq!(stepper.advance(f64::NAN), 0);
+    assert_eq!(stepper.alpha(), 0.25);
+}
+
+#[test]
+fn spiral_of_death_is_clamped() {
+    let mut stepper = FixedStepper::new(0.25, 4);
+
+    // A frame of 10 steps runs only 4 and drops the backlog, but keeps the fraction
+    assert_eq!(stepper.advance(2.5 + 0.125), 4);
+    assert_eq!(stepper.alpha(), 0.5);
+    assert_eq!(stepper.advance(0.125), 1);
+    assert_eq!(stepper.alpha(), 0.0);
+}
+
+#[test]
+fn alpha_stays_below_one() {
+    let mut stepper = FixedStepper::new(1.0 / 60.0, 8);
+
+    for i in 0..1000 {
+        stepper.advance(0.001 * (i % 37) as f64);
+        let alpha = stepper.alpha();
+        assert!((0.0..1.0).contains(&alpha), "alpha is {alpha}");
+    }
+}
+
+#[test]
+#[should_panic]
+fn zero_timestep_is_rejected() {
+    FixedStepper::new(0.0, 8);
+}
+
+#[test]
+#[should_panic]
+fn nan_timestep_is_rejected() {
+    FixedStepper::new(f64::NAN, 8);
+}
+
+#[test]
+#[should_panic]
+fn zero_max_steps_is_rejected() {
+    FixedStepper::new(1.0 / 60.0, 0);
+}
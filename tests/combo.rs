@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+
+use dioteko::core::combo::{Chord, SequenceDetector};
+use dioteko::core::input_state::InputState;
+use dioteko::core::keyboard::Key;
+
+// Makes the input state of consecutive frames from the keys held in each frame
+#[derive(Default)]
+struct Keyboard {
+    held: HashSet<Key>,
+}
+
+impl Keyboard {
+    fn frame(&mut self, held: &[Key]) -> InputState {
+        let held = held.iter().copied().collect::<HashSet<_>>();
+        let mut input = InputState::new();
+
+        for &key in &held {
+            input = if self.held.contains(&key) {
+                input.with_key_down(key)
+            } else {
+                input.with_key_pressed(key)
+            };
+        }
+        for &key in self.held.difference(&held) {
+            input = input.with_key_released(key);
+        }
+
+        self.held = held;
+        input
+    }
+}
+
+// Feeds one frame per entry, 0.1 seconds apart, and returns the frames in which
+// the sequence has been completed
+fn run(detector: &mut SequenceDetector, frames: &[&[Key]]) -> Vec<usize> {
+    let mut keyboard = Keyboard::default();
+    (0..frames.len())
+        .filter(|&i| detector.update_from(&keyboard.frame(frames[i]), i as f64 * 0.1))
+        .collect()
+}
+
+const NONE: &[Key] = &[];
+
+#[test]
+fn chord_in_any_order() {
+    let save = Chord::new([Key::LeftControl, Key::S]);
+    let mut keyboard = Keyboard::default();
+
+    assert!(!save.is_pressed_in(&keyboard.frame(&[Key::S])));
+    assert!(save.is_pressed_in(&keyboard.frame(&[Key::S, Key::LeftControl])));
+    // Holding the chord does not complete it again
+    assert!(!save.is_pressed_in(&keyboard.frame(&[Key::S, Key::LeftControl])));
+    assert!(!save.is_pressed_in(&keyboard.frame(&[Key::LeftControl])));
+    assert!(save.is_pressed_in(&keyboard.frame(&[Key::S, Key::LeftControl])));
+}
+
+#[test]
+fn double_tap() {
+    let mut dash = SequenceDetector::new([Key::D, Key::D]);
+
+    assert_eq!(run(&mut dash, &[&[Key::D], NONE, &[Key::D]]), [2]);
+    // Holding the key is a single tap
+    assert_eq!(run(&mut dash, &[&[Key::D], &[Key::D], &[Key::D]]), []);
+}
+
+#[test]
+fn overlapping_chords() {
+    let quarter_circle = [
+        Chord::new([Key::Down]),
+        Chord::new([Key::Down, Key::Right]),
+        Chord::new([Key::Right]),
+    ];
+    let mut fireball = SequenceDetector::new(quarter_circle);
+
+    let frames: &[&[Key]] = &[&[Key::Down], &[Key::Down, Key::Right], &[Key::Right]];
+    assert_eq!(run(&mut fireball, frames), [2]);
+    assert_eq!(fireball.progress(), 0);
+}
+
+#[test]
+fn rolled_over_keys() {
+    let mut detector = SequenceDetector::new([Key::Up, Key::Down]);
+
+    // Down is pressed before Up is released, so the step is entered on the release
+    let frames: &[&[Key]] = &[&[Key::Up], &[Key::Up, Key::Down], &[Key::Down]];
+    assert_eq!(run(&mut detector, frames), [2]);
+}
+
+#[test]
+fn repeated_prefix() {
+    let mut konami = SequenceDetector::new([Key::Up, Key::Up, Key::Down, Key::Down]);
+
+    let frames: &[&[Key]] = &[
+        &[Key::Up],
+        NONE,
+        &[Key::Up],
+        NONE,
+        &[Key::Up],
+        NONE,
+        &[Key::Down],
+        NONE,
+        &[Key::Down],
+    ];
+    assert_eq!(run(&mut konami, frames), [8]);
+
+    // A mismatch with no matching prefix starts over
+    let mut abab = SequenceDetector::new([Key::A, Key::B, Key::A, Key::C]);
+    let frames: &[&[Key]] = &[
+        &[Key::A],
+        &[Key::B],
+        &[Key::A],
+        &[Key::B],
+        &[Key::A],
+        &[Key::C],
+    ];
+    assert_eq!(run(&mut abab, frames), [5]);
+}
+
+#[test]
+fn out_of_order_step_breaks_the_sequence() {
+    let mut detector = SequenceDetector::new([Key::A, Key::B, Key::C]);
+
+    // The second A starts a new attempt, which C breaks again
+    let frames: &[&[Key]] = &[&[Key::A], &[Key::B], &[Key::A]];
+    assert_eq!(run(&mut detector, frames), []);
+    assert_eq!(detector.progress(), 1);
+
+    let mut detector = SequenceDetector::new([Key::A, Key::B, Key::C]);
+    let frames: &[&[Key]] = &[&[Key::A], &[Key::B], &[Key::A], &[Key::C]];
+    assert_eq!(run(&mut detector, frames), []);
+    assert_eq!(detector.progress(), 0);
+}
+
+#[test]
+fn unrelated_keys_are_ignored() {
+    let mut dash = SequenceDetector::new([Key::D, Key::D]);
+
+    let frames: &[&[Key]] = &[&[Key::D], &[Key::W], &[Key::W, Key::D]];
+    assert_eq!(run(&mut dash, frames), [2]);
+}
+
+#[test]
+fn timeout_expires() {
+    let mut dash = SequenceDetector::new([Key::D, Key::D]).with_max_gap(0.25);
+    let mut keyboard = Keyboard::default();
+
+    assert!(!dash.update_from(&keyboard.frame(&[Key::D]), 0.0));
+    assert!(!dash.update_from(&keyboard.frame(NONE), 0.1));
+    assert!(!dash.update_from(&keyboard.frame(&[Key::D]), 0.5));
+    // The late press started a new attempt
+    assert_eq!(dash.progress(), 1);
+    assert!(!dash.update_from(&keyboard.frame(NONE), 0.6));
+    assert!(dash.update_from(&keyboard.frame(&[Key::D]), 0.7));
+}
+
+#[test]
+fn reset() {
+    let mut dash = SequenceDetector::new([Key::D, Key::D]);
+    let mut keyboard = Keyboard::default();
+
+    assert!(!dash.update_from(&keyboard.frame(&[Key::D]), 0.0));
+    assert!(!dash.update_from(&keyboard.frame(NONE), 0.1));
+    dash.reset();
+    assert_eq!(dash.progress(), 0);
+    assert!(!dash.update_from(&keyboard.frame(&[Key::D]), 0.2));
+
+    let mut empty = SequenceDetector::new(Vec::<Chord>::new());
+    assert!(!empty.update_from(&InputState::new(), 0.0));
+}
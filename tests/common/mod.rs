@@ -0,0 +1,28 @@
+//! Assertions shared by the linear algebra tests
+
+// Every test crate only uses some of them
+#![allow(dead_code)]
+
+use dioteko::core::linalg::{Matrix, Quaternion, Vector3};
+
+pub const EPSILON: f32 = 1e-5;
+
+pub fn assert_vector3_eq(lhs: Vector3, rhs: Vector3) {
+    assert!(
+        (lhs.x - rhs.x).abs() < EPSILON
+            && (lhs.y - rhs.y).abs() < EPSILON
+            && (lhs.z - rhs.z).abs() < EPSILON,
+        "{lhs:?} != {rhs:?}"
+    );
+}
+
+pub fn assert_matrix_eq(lhs: Matrix, rhs: Matrix) {
+    let (lhs_array, rhs_array) = (lhs.to_array(), rhs.to_array());
+    for (l, r) in lhs_array.iter().zip(rhs_array.iter()) {
+        assert!((l - r).abs() < EPSILON, "{lhs:?} != {rhs:?}");
+    }
+}
+
+pub fn assert_quaternion_eq(lhs: Quaternion, rhs: Quaternion) {
+    assert!(lhs.approx_eq(rhs), "{lhs:?} != {rhs:?}");
+}
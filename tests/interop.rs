@@ -0,0 +1,155 @@
+#![cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+
+use dioteko::core::linalg::{Matrix, Quaternion, Vector2, Vector3, Vector4};
+use dioteko::core::rectangle::Rectangle;
+
+fn vector2() -> Vector2 {
+    Vector2::new(1.0, -2.0)
+}
+
+fn vector3() -> Vector3 {
+    Vector3::new(1.0, -2.0, 3.5)
+}
+
+fn vector4() -> Vector4 {
+    Vector4::new(1.0, -2.0, 3.5, 0.25)
+}
+
+fn quaternion() -> Quaternion {
+    Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7)
+}
+
+fn matrix() -> Matrix {
+    Matrix::translate(1.0, 2.0, 3.0) * Matrix::rotate_x(0.5) * Matrix::scale(2.0, 3.0, 4.0)
+}
+
+fn rectangle() -> Rectangle {
+    Rectangle {
+        x: 10.0,
+        y: 20.0,
+        width: 300.0,
+        height: 400.0,
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam {
+    use super::*;
+    use ::glam::{Mat4, Quat, Vec2, Vec3, Vec4};
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(Vector2::from(Vec2::from(vector2())), vector2());
+        assert_eq!(Vector3::from(Vec3::from(vector3())), vector3());
+        assert_eq!(Vector4::from(Vec4::from(vector4())), vector4());
+        assert_eq!(Quaternion::from(Quat::from(quaternion())), quaternion());
+        assert_eq!(Matrix::from(Mat4::from(matrix())), matrix());
+        assert_eq!(
+            Rectangle::from(<(Vec2, Vec2)>::from(rectangle())),
+            rectangle()
+        );
+    }
+
+    #[test]
+    fn same_meaning() {
+        let mat = Mat4::from(matrix());
+        let point = Vector3::from(mat.transform_point3(vector3().into()));
+        assert!(point.approx_eq(matrix() * vector3()));
+
+        let qua = Quat::from(quaternion());
+        let rotated = Vector3::from(qua * Vec3::from(vector3()));
+        assert!(rotated.approx_eq(quaternion() * vector3()));
+
+        let (position, size) = rectangle().into();
+        assert_eq!(
+            (position, size),
+            (Vec2::new(10.0, 20.0), Vec2::new(300.0, 400.0))
+        );
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint {
+    use super::*;
+    use ::mint::{ColumnMatrix4, Point2, Point3, RowMatrix4};
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(Vector2::from(::mint::Vector2::from(vector2())), vector2());
+        assert_eq!(Vector2::from(Point2::from(vector2())), vector2());
+        assert_eq!(Vector3::from(::mint::Vector3::from(vector3())), vector3());
+        assert_eq!(Vector3::from(Point3::from(vector3())), vector3());
+        assert_eq!(Vector4::from(::mint::Vector4::from(vector4())), vector4());
+        assert_eq!(
+            Quaternion::from(::mint::Quaternion::from(quaternion())),
+            quaternion()
+        );
+        assert_eq!(Matrix::from(ColumnMatrix4::from(matrix())), matrix());
+        assert_eq!(Matrix::from(RowMatrix4::from(matrix())), matrix());
+        assert_eq!(
+            Rectangle::from(<(Point2<f32>, ::mint::Vector2<f32>)>::from(rectangle())),
+            rectangle()
+        );
+    }
+
+    #[test]
+    fn same_meaning() {
+        let qua = ::mint::Quaternion::from(quaternion());
+        assert_eq!(qua.s, quaternion().w);
+
+        // The translation is the last column
+        let mat = RowMatrix4::from(matrix());
+        assert_eq!((mat.x.w, mat.y.w, mat.z.w), (1.0, 2.0, 3.0));
+        let mat = ColumnMatrix4::from(matrix());
+        assert_eq!((mat.w.x, mat.w.y, mat.w.z), (1.0, 2.0, 3.0));
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra {
+    use super::*;
+    use ::nalgebra::{Matrix4, Point2, Point3, UnitQuaternion};
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(
+            Vector2::from(::nalgebra::Vector2::from(vector2())),
+            vector2()
+        );
+        assert_eq!(Vector2::from(Point2::from(vector2())), vector2());
+        assert_eq!(
+            Vector3::from(::nalgebra::Vector3::from(vector3())),
+            vector3()
+        );
+        assert_eq!(Vector3::from(Point3::from(vector3())), vector3());
+        assert_eq!(
+            Vector4::from(::nalgebra::Vector4::from(vector4())),
+            vector4()
+        );
+        assert_eq!(
+            Quaternion::from(::nalgebra::Quaternion::from(quaternion())),
+            quaternion()
+        );
+        assert!(Quaternion::from(UnitQuaternion::from(quaternion())).approx_eq(quaternion()));
+        assert_eq!(Matrix::from(Matrix4::from(matrix())), matrix());
+        assert_eq!(
+            Rectangle::from(<(Point2<f32>, ::nalgebra::Vector2<f32>)>::from(rectangle())),
+            rectangle()
+        );
+    }
+
+    #[test]
+    fn same_meaning() {
+        let mat = Matrix4::from(matrix());
+        let point = Vector3::from(mat.transform_point(&Point3::from(vector3())));
+        assert!(point.approx_eq(matrix() * vector3()));
+
+        let qua = UnitQuaternion::from(quaternion());
+        let rotated = Vector3::from(qua * ::nalgebra::Vector3::from(vector3()));
+        assert!(rotated.approx_eq(quaternion() * vector3()));
+
+        // A non-normalized quaternion is normalized
+        let qua = UnitQuaternion::from(2.0 * quaternion());
+        assert!(Quaternion::from(qua).approx_eq(quaternion()));
+    }
+}
@@ -0,0 +1,200 @@
+mod common;
+
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use dioteko::core::linalg::{Matrix, Vector3, Vector4};
+
+use common::{assert_matrix_eq, assert_vector3_eq, EPSILON};
+
+#[test]
+fn layout_is_column_major() {
+    let mat = Matrix::translate(1.0, 2.0, 3.0);
+    assert_eq!(
+        mat.to_array(),
+        [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            1.0, 2.0, 3.0, 1.0,
+        ]
+    );
+    assert_eq!(Matrix::from_array(mat.to_array()), mat);
+    assert_eq!(Matrix::identity(), Matrix::IDENTITY);
+}
+
+#[test]
+fn transform_points() {
+    let point = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_vector3_eq(
+        Matrix::translate(1.0, -1.0, 2.0) * point,
+        Vector3::new(2.0, 1.0, 5.0),
+    );
+    assert_vector3_eq(
+        Matrix::scale(2.0, 3.0, 4.0) * point,
+        Vector3::new(2.0, 6.0, 12.0),
+    );
+    assert_vector3_eq(
+        Matrix::rotate_z(FRAC_PI_2) * Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    assert_vector3_eq(
+        Matrix::rotate_x(FRAC_PI_2) * Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    );
+    assert_vector3_eq(
+        Matrix::rotate_y(FRAC_PI_2) * Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(1.0, 0.0, 0.0),
+    );
+
+    // A direction is not translated
+    let dir = Matrix::translate(5.0, 5.0, 5.0) * Vector4::new(1.0, 0.0, 0.0, 0.0);
+    assert_eq!((dir.x, dir.y, dir.z, dir.w), (1.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn rotate_around_axis() {
+    let angle = 0.7;
+    assert_matrix_eq(
+        Matrix::rotate(Vector3::new(1.0, 0.0, 0.0), angle),
+        Matrix::rotate_x(angle),
+    );
+    assert_matrix_eq(
+        Matrix::rotate(Vector3::new(0.0, 2.0, 0.0), angle),
+        Matrix::rotate_y(angle),
+    );
+    assert_matrix_eq(
+        Matrix::rotate(Vector3::new(0.0, 0.0, 1.0), angle),
+        Matrix::rotate_z(angle),
+    );
+}
+
+#[test]
+fn rotate_euler_angles() {
+    let angles = Vector3::new(0.3, -0.5, 1.1);
+    let (x, y, z) = (
+        Matrix::rotate_x(angles.x),
+        Matrix::rotate_y(angles.y),
+        Matrix::rotate_z(angles.z),
+    );
+
+    assert_matrix_eq(Matrix::rotate_zyx(angles), z * y * x);
+    assert_matrix_eq(Matrix::rotate_xyz(angles), x * y * z);
+    // The inverse of a rotation is its transpose
+    assert_matrix_eq(
+        Matrix::rotate_xyz(angles),
+        Matrix::rotate_zyx(Vector3::new(-angles.x, -angles.y, -angles.z)).transpose(),
+    );
+}
+
+#[test]
+fn multiply_matches_raymath_order() {
+    let scale = Matrix::scale(2.0, 2.0, 2.0);
+    let translate = Matrix::translate(1.0, 0.0, 0.0);
+    let point = Vector3::new(1.0, 0.0, 0.0);
+
+    // MatrixMultiply(scale, translate) scales first, then translates
+    assert_vector3_eq(
+        scale.multiply(translate) * point,
+        Vector3::new(3.0, 0.0, 0.0),
+    );
+    assert_vector3_eq((translate * scale) * point, translate * (scale * point));
+
+    let mut mat = scale;
+    mat *= translate;
+    assert_matrix_eq(mat, scale * translate);
+}
+
+#[test]
+fn add_and_subtract() {
+    let mat = Matrix::translate(1.0, 2.0, 3.0);
+    assert_matrix_eq(mat + mat - mat, mat);
+    assert_eq!((mat + mat).m13, 4.0);
+}
+
+#[test]
+fn transpose_determinant_and_trace() {
+    let mat = Matrix::rotate_xyz(Vector3::new(0.1, 0.2, 0.3)) * Matrix::translate(4.0, 5.0, 6.0);
+
+    assert_matrix_eq(mat.transpose().transpose(), mat);
+    assert_eq!(mat.transpose().m1, mat.m4);
+    assert_eq!(mat.transpose().m12, mat.m3);
+
+    assert!((Matrix::scale(2.0, 3.0, 4.0).determinant() - 24.0).abs() < EPSILON);
+    assert!((mat.determinant() - 1.0).abs() < EPSILON);
+    assert!((mat.determinant() - mat.transpose().determinant()).abs() < EPSILON);
+    assert_eq!(Matrix::scale(2.0, 3.0, 4.0).trace(), 10.0);
+}
+
+#[test]
+fn invert() {
+    let mat = Matrix::scale(2.0, 0.5, 4.0)
+        * Matrix::rotate(Vector3::new(1.0, 1.0, 0.0), FRAC_PI_4)
+        * Matrix::translate(-3.0, 1.0, 2.0);
+
+    assert_matrix_eq(mat * mat.invert(), Matrix::IDENTITY);
+    assert_matrix_eq(mat.invert() * mat, Matrix::IDENTITY);
+    assert_matrix_eq(
+        Matrix::translate(1.0, 2.0, 3.0).invert(),
+        Matrix::translate(-1.0, -2.0, -3.0),
+    );
+}
+
+#[test]
+fn frustum_and_perspective() {
+    // MatrixPerspective(90 degrees, 1, 1, 3) is MatrixFrustum(-1, 1, -1, 1, 1, 3)
+    let expected = Matrix::from_array([
+        1.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, -2.0, -1.0, //
+        0.0, 0.0, -3.0, 0.0,
+    ]);
+    assert_matrix_eq(Matrix::frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 3.0), expected);
+    assert_matrix_eq(
+        Matrix::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 3.0),
+        expected,
+    );
+
+    // The near plane is mapped to -1 and the far plane to 1
+    let proj = Matrix::perspective(1.0, 16.0 / 9.0, 0.1, 100.0);
+    let near = proj * Vector4::new(0.0, 0.0, -0.1, 1.0);
+    let far = proj * Vector4::new(0.0, 0.0, -100.0, 1.0);
+    assert!((near.z / near.w + 1.0).abs() < 1e-4);
+    assert!((far.z / far.w - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn ortho() {
+    let proj = Matrix::ortho(0.0, 800.0, 600.0, 0.0, -1.0, 1.0);
+    let expected = Matrix {
+        m0: 2.0 / 800.0,
+        m5: -2.0 / 600.0,
+        m10: -1.0,
+        m12: -1.0,
+        m13: 1.0,
+        ..Matrix::IDENTITY
+    };
+    assert_matrix_eq(proj, expected);
+    assert_vector3_eq(
+        proj * Vector3::new(800.0, 600.0, 0.0),
+        Vector3::new(1.0, -1.0, 0.0),
+    );
+}
+
+#[test]
+fn look_at() {
+    let up = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_matrix_eq(
+        Matrix::look_at(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), up),
+        Matrix::translate(0.0, 0.0, -5.0),
+    );
+
+    // The target is in front of the camera, that is, on the negative Z axis
+    let eye = Vector3::new(3.0, 4.0, -2.0);
+    let target = Vector3::new(-1.0, 0.5, 7.0);
+    let view = Matrix::look_at(eye, target, up);
+    assert_vector3_eq(view * eye, Vector3::new(0.0, 0.0, 0.0));
+    let seen = view * target;
+    assert!(seen.x.abs() < 1e-4 && seen.y.abs() < 1e-4 && seen.z < 0.0);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use dioteko::core::linalg::{Matrix, Quaternion, Vector3};
+
+use common::{assert_quaternion_eq, assert_vector3_eq, EPSILON};
+
+#[test]
+fn identity() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Quaternion::default(), Quaternion::IDENTITY);
+    assert_eq!(Quaternion::identity().rotate(v), v);
+    assert_eq!(Quaternion::IDENTITY.to_matrix(), Matrix::IDENTITY);
+    assert_eq!(
+        Quaternion::from_matrix(Matrix::IDENTITY),
+        Quaternion::IDENTITY
+    );
+    assert_eq!(
+        Quaternion::from_axis_angle(Vector3::ZERO, 1.0),
+        Quaternion::IDENTITY
+    );
+}
+
+#[test]
+fn rotate_vector() {
+    let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2);
+
+    assert!((q.length() - 1.0).abs() < EPSILON);
+    assert_vector3_eq(
+        q.rotate(Vector3::new(1.0, 0.0, 0.0)),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    assert_vector3_eq(
+        q * Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn axis_angle_round_trip() {
+    let axis = Vector3::new(1.0, -2.0, 0.5).normalize();
+    let (back_axis, back_angle) = Quaternion::from_axis_angle(axis, 0.8).to_axis_angle();
+
+    assert_vector3_eq(back_axis, axis);
+    assert!((back_angle - 0.8).abs() < EPSILON);
+}
+
+#[test]
+fn matches_matrix_rotation() {
+    let axis = Vector3::new(1.0, 1.0, 0.0);
+    let q = Quaternion::from_axis_angle(axis, FRAC_PI_4);
+    let mat = Matrix::rotate(axis, FRAC_PI_4);
+    let v = Vector3::new(0.3, -1.2, 2.0);
+
+    assert_vector3_eq(q.rotate(v), mat * v);
+    assert_vector3_eq(q.to_matrix() * v, mat * v);
+    assert_quaternion_eq(Quaternion::from_matrix(mat), q);
+
+    // Every branch of the conversion from a matrix
+    for angle in [0.5, 3.0] {
+        for axis in [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ] {
+            let q = Quaternion::from_axis_angle(axis, angle);
+            assert_quaternion_eq(Quaternion::from_matrix(q.to_matrix()), q);
+        }
+    }
+}
+
+#[test]
+fn euler_round_trip() {
+    let (pitch, yaw, roll) = (0.3, -0.5, 1.1);
+    let q = Quaternion::from_euler(pitch, yaw, roll);
+
+    assert_vector3_eq(q.to_euler(), Vector3::new(pitch, yaw, roll));
+    // Matches MatrixRotateZYX, which rotates around X first
+    assert_quaternion_eq(
+        Quaternion::from_matrix(Matrix::rotate_zyx(Vector3::new(pitch, yaw, roll))),
+        q,
+    );
+}
+
+#[test]
+fn multiply_and_invert() {
+    let a = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 0.4);
+    let b = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 1.3);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    // `b` is applied first
+    assert_vector3_eq((a * b).rotate(v), a.rotate(b.rotate(v)));
+    assert_quaternion_eq(a * a.invert(), Quaternion::IDENTITY);
+    assert_quaternion_eq(a.invert(), a.conjugate());
+    assert_quaternion_eq(a * b / b, a);
+
+    let unnormalized = 2.0 * a;
+    assert_quaternion_eq(unnormalized * unnormalized.invert(), Quaternion::IDENTITY);
+    assert_quaternion_eq(unnormalized.normalize(), a);
+}
+
+#[test]
+fn from_vector3_to_vector3() {
+    let from = Vector3::new(1.0, 0.0, 0.0);
+    let to = Vector3::new(0.0, 0.0, 1.0);
+    let q = Quaternion::from_vector3_to_vector3(from, to);
+
+    assert_vector3_eq(q.rotate(from), to);
+}
+
+#[test]
+fn interpolation() {
+    let axis = Vector3::new(0.0, 1.0, 0.0);
+    let a = Quaternion::IDENTITY;
+    let b = Quaternion::from_axis_angle(axis, FRAC_PI_2);
+    let halfway = Quaternion::from_axis_angle(axis, FRAC_PI_4);
+
+    assert_quaternion_eq(a.slerp(b, 0.0), a);
+    assert_quaternion_eq(a.slerp(b, 1.0), b);
+    assert_quaternion_eq(a.slerp(b, 0.5), halfway);
+    assert_quaternion_eq(a.nlerp(b, 0.5), halfway);
+    assert_eq!(a.lerp(b, 0.5), 0.5 * (a + b));
+
+    // The shortest arc is taken even if `b` is negated
+    assert_quaternion_eq(a.slerp(-b, 0.5), halfway);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "serde")]
+
+use dioteko::core::action::{Binding, Input};
+use dioteko::core::color::{self, Color};
+use dioteko::core::keyboard::Key;
+use dioteko::core::linalg::{Matrix, Quaternion, Vector2, Vector3};
+use dioteko::core::mouse::MouseButton;
+use dioteko::core::rectangle::Rectangle;
+use dioteko::core::touch::Gesture;
+use dioteko::core::window::WindowFlags;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn value_types() {
+    let vec = Vector3::new(1.0, -2.0, 0.5);
+    assert_eq!(
+        serde_json::to_string(&vec).unwrap(),
+        r#"{"x":1.0,"y":-2.0,"z":0.5}"#
+    );
+    assert_eq!(round_trip(&vec), vec);
+    assert_eq!(round_trip(&Vector2::ONE), Vector2::ONE);
+
+    let qua = Quaternion::from_axis_angle(vec, 0.3);
+    assert_eq!(round_trip(&qua), qua);
+    let mat = Matrix::translate(1.0, 2.0, 3.0);
+    assert_eq!(round_trip(&mat), mat);
+
+    let rec = Rectangle {
+        x: 1.0,
+        y: 2.0,
+        width: 30.0,
+        height: 40.0,
+    };
+    assert_eq!(round_trip(&rec), rec);
+}
+
+#[test]
+fn color() {
+    assert_eq!(
+        serde_json::to_string(&color::RED).unwrap(),
+        "[230,41,55,255]"
+    );
+    assert_eq!(round_trip(&color::RED), color::RED);
+
+    let parse = |json: &str| serde_json::from_str::<Color>(json);
+    assert_eq!(parse(r##""#E62937FF""##).unwrap(), color::RED);
+    assert_eq!(parse(r##""#e6293780""##).unwrap(), Color(230, 41, 55, 128));
+    assert_eq!(parse(r##""#E62937""##).unwrap(), color::RED);
+    assert!(parse(r##""E62937FF""##).is_err());
+    assert!(parse(r##""#E6293""##).is_err());
+    assert!(parse(r##""#+62937FF""##).is_err());
+    assert!(parse("[1, 2, 3]").is_err());
+
+    assert_eq!(color::RED.to_string(), "#E62937FF");
+    assert_eq!("#E62937FF".parse::<Color>().unwrap(), color::RED);
+}
+
+#[test]
+fn enums_use_names() {
+    assert_eq!(
+        serde_json::to_string(&Key::LeftControl).unwrap(),
+        r#""LeftControl""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Key>(r#""space""#).unwrap(),
+        Key::Space
+    );
+    assert!(serde_json::from_str::<Key>(r#""NoSuchKey""#).is_err());
+    assert!(serde_json::from_str::<Key>("32").is_err());
+
+    for button in MouseButton::iter() {
+        assert_eq!(round_trip(&button), button);
+    }
+
+    let binding = Binding::negated(Input::Keys(vec![Key::LeftShift, Key::A]));
+    assert_eq!(round_trip(&binding), binding);
+}
+
+#[test]
+fn flags_use_bits() {
+    let flags = WindowFlags::VSYNC_HINT | WindowFlags::RESIZABLE;
+    assert_eq!(
+        serde_json::to_string(&flags).unwrap(),
+        flags.bits().to_string()
+    );
+    assert_eq!(round_trip(&flags), flags);
+    assert_eq!(round_trip(&Gesture::empty()), Gesture::empty());
+
+    let unknown = (!Gesture::all().bits()).to_string();
+    assert!(serde_json::from_str::<Gesture>(&unknown).is_err());
+}
@@ -0,0 +1,200 @@
+use dioteko::core::event::Event;
+use dioteko::core::input_state::InputState;
+use dioteko::core::keyboard::Key;
+use dioteko::core::text_input::TextInput;
+
+// One frame in which `key` is pressed while the modifiers are held
+fn press(key: Key, modifiers: &[Key]) -> InputState {
+    modifiers
+        .iter()
+        .fold(InputState::new(), |input, &modifier| {
+            input.with_key_down(modifier)
+        })
+        .with_key_down(key)
+        .with_key_pressed(key)
+}
+
+fn type_text(input: &mut TextInput, text: &str) {
+    // One character per frame, like a user typing
+    for ch in text.chars() {
+        input.update_from(&InputState::new().with_chars(ch.encode_utf8(&mut [0; 4])));
+    }
+}
+
+const CTRL: &[Key] = &[Key::LeftControl];
+const SHIFT: &[Key] = &[Key::LeftShift];
+const CTRL_SHIFT: &[Key] = &[Key::LeftControl, Key::LeftShift];
+
+#[test]
+fn typing() {
+    let mut input = TextInput::new();
+    assert!(input.update_from(&InputState::new().with_chars("héllo")));
+    assert_eq!(input.text(), "héllo");
+    assert_eq!(input.cursor(), "héllo".len());
+
+    // Control characters are dropped, and a frame without input changes nothing
+    assert!(!input.update_from(&InputState::new().with_chars("\n\t")));
+    assert!(!input.update_from(&InputState::new()));
+    assert_eq!(input.text(), "héllo");
+}
+
+#[test]
+fn graphemes_are_never_split() {
+    // "e" with a combining acute accent, and a thumbs up with a skin tone
+    let accent = "e\u{301}";
+    let thumbs_up = "\u{1F44D}\u{1F3FD}";
+    let mut input = TextInput::with_text(&format!("a{accent}{thumbs_up}b"));
+
+    input.update_from(&press(Key::Left, &[]));
+    input.update_from(&press(Key::Left, &[]));
+    assert_eq!(input.cursor(), 1 + accent.len());
+
+    input.update_from(&press(Key::Backspace, &[]));
+    assert_eq!(input.text(), format!("a{thumbs_up}b"));
+    assert_eq!(input.cursor(), 1);
+
+    input.update_from(&press(Key::Delete, &[]));
+    assert_eq!(input.text(), "ab");
+
+    // A combining mark typed after a letter joins its grapheme
+    let mut input = TextInput::new();
+    type_text(&mut input, "x\u{301}");
+    input.update_from(&press(Key::Backspace, &[]));
+    assert_eq!(input.text(), "");
+}
+
+#[test]
+fn word_jumps() {
+    let mut input = TextInput::with_text("hello brave  world");
+
+    input.update_from(&press(Key::Left, CTRL));
+    assert_eq!(input.cursor(), "hello brave  ".len());
+    input.update_from(&press(Key::Left, CTRL));
+    assert_eq!(input.cursor(), "hello ".len());
+    input.update_from(&press(Key::Right, CTRL));
+    assert_eq!(input.cursor(), "hello brave".len());
+
+    input.update_from(&press(Key::Home, &[]));
+    input.update_from(&press(Key::Delete, CTRL));
+    assert_eq!(input.text(), " brave  world");
+
+    input.update_from(&press(Key::End, &[]));
+    input.update_from(&press(Key::Backspace, CTRL));
+    assert_eq!(input.text(), " brave  ");
+}
+
+#[test]
+fn selection_is_replaced() {
+    let mut input = TextInput::with_text("hello world");
+
+    input.update_from(&press(Key::Left, CTRL_SHIFT));
+    assert_eq!(input.selected_text(), Some("world"));
+    type_text(&mut input, "there");
+    assert_eq!(input.text(), "hello there");
+    assert_eq!(input.selection(), None);
+
+    input.update_from(&press(Key::Home, SHIFT));
+    assert_eq!(input.selected_text(), Some("hello there"));
+    // Moving without shift collapses the selection to its start
+    input.update_from(&press(Key::Left, &[]));
+    assert_eq!((input.cursor(), input.selection()), (0, None));
+
+    input.update_from(&press(Key::A, CTRL));
+    input.update_from(&press(Key::Backspace, &[]));
+    assert_eq!(input.text(), "");
+}
+
+#[test]
+fn consecutive_typing_is_one_undo_step() {
+    let mut input = TextInput::new();
+    type_text(&mut input, "abc");
+    input.update_from(&press(Key::Backspace, &[]));
+    input.update_from(&press(Key::Backspace, &[]));
+    assert_eq!(input.text(), "a");
+
+    // The deletions are undone together, then the typing
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "abc");
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "");
+    assert!(!input.undo());
+}
+
+#[test]
+fn moving_the_cursor_breaks_the_undo_step() {
+    let mut input = TextInput::new();
+    type_text(&mut input, "ab");
+    input.update_from(&press(Key::Left, &[]));
+    type_text(&mut input, "c");
+    assert_eq!(input.text(), "acb");
+
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "ab");
+    assert_eq!(input.cursor(), 1);
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "");
+}
+
+#[test]
+fn paste_is_its_own_undo_step() {
+    let mut input = TextInput::new();
+    type_text(&mut input, "ab");
+    input.paste_text("XY");
+    type_text(&mut input, "c");
+    assert_eq!(input.text(), "abXYc");
+
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "abXY");
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "ab");
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "");
+}
+
+#[test]
+fn new_edit_clears_redo() {
+    let mut input = TextInput::new();
+    type_text(&mut input, "ab");
+    input.update_from(&press(Key::Z, CTRL));
+    assert_eq!(input.text(), "");
+
+    input.update_from(&press(Key::Y, CTRL));
+    assert_eq!(input.text(), "ab");
+    input.update_from(&press(Key::Z, CTRL));
+    input.update_from(&press(Key::Z, CTRL_SHIFT));
+    assert_eq!(input.text(), "ab");
+
+    input.update_from(&press(Key::Z, CTRL));
+    type_text(&mut input, "x");
+    assert!(!input.redo());
+    assert_eq!(input.text(), "x");
+}
+
+#[test]
+fn events_track_the_modifiers() {
+    let mut input = TextInput::with_text("hello world");
+
+    assert!(!input.handle_event(&Event::KeyDown(Key::LeftControl)));
+    assert!(input.handle_event(&Event::KeyDown(Key::Left)));
+    assert_eq!(input.cursor(), "hello ".len());
+
+    // Both sides count, and shift extends the selection
+    input.handle_event(&Event::KeyDown(Key::Rightshift));
+    input.handle_event(&Event::KeyRepeat(Key::Left));
+    assert_eq!(input.selected_text(), Some("hello "));
+
+    input.handle_event(&Event::KeyUp(Key::LeftControl));
+    input.handle_event(&Event::KeyUp(Key::Rightshift));
+    input.handle_event(&Event::KeyDown(Key::Right));
+    assert_eq!((input.cursor(), input.selection()), ("hello ".len(), None));
+
+    // Losing the focus releases every modifier
+    input.handle_event(&Event::KeyDown(Key::LeftControl));
+    input.handle_event(&Event::FocusChanged(false));
+    input.handle_event(&Event::KeyDown(Key::Right));
+    assert_eq!(input.cursor(), "hello w".len());
+
+    assert!(input.handle_event(&Event::Char('!')));
+    assert!(!input.handle_event(&Event::KeyDown(Key::F1)));
+    assert_eq!(input.text(), "hello w!orld");
+}
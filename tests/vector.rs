@@ -0,0 +1,129 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use dioteko::core::linalg::{Vector2, Vector3, Vector4};
+
+#[test]
+fn operators() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(4.0, 5.0, 6.0);
+
+    assert_eq!(a + b, Vector3::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vector3::new(3.0, 3.0, 3.0));
+    assert_eq!(a * 2.0, 2.0 * a);
+    assert_eq!(a * b, Vector3::new(4.0, 10.0, 18.0));
+    assert_eq!(b / 2.0, Vector3::new(2.0, 2.5, 3.0));
+    assert_eq!(b / a, Vector3::new(4.0, 2.5, 2.0));
+    assert_eq!(-a, Vector3::new(-1.0, -2.0, -3.0));
+
+    let mut c = a;
+    c *= 2.0;
+    c /= Vector3::new(2.0, 4.0, 6.0);
+    assert_eq!(c, Vector3::new(1.0, 1.0, 1.0));
+
+    assert_eq!(Vector2::default(), Vector2::ZERO);
+    assert_eq!([a, b].iter().sum::<Vector3>(), a + b);
+    assert_eq!([a, b].into_iter().sum::<Vector3>(), a + b);
+}
+
+#[test]
+fn conversions_and_index() {
+    let mut v = Vector4::from([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(v, Vector4::from((1.0, 2.0, 3.0, 4.0)));
+    assert_eq!(<[f32; 4]>::from(v), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(<(f32, f32, f32, f32)>::from(v), (1.0, 2.0, 3.0, 4.0));
+
+    assert_eq!(v[2], 3.0);
+    v[3] = 0.0;
+    assert_eq!(v.w, 0.0);
+    assert_eq!(Vector2::LEN, 2);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let _ = Vector2::ONE[2];
+}
+
+#[test]
+fn length_and_distance() {
+    let v = Vector2::new(3.0, 4.0);
+
+    assert_eq!(v.dot(Vector2::new(1.0, 1.0)), 7.0);
+    assert_eq!(v.length(), 5.0);
+    assert_eq!(v.length_sqr(), 25.0);
+    assert_eq!(v.normalize(), Vector2::new(0.6, 0.8));
+    assert_eq!(Vector2::ZERO.normalize(), Vector2::ZERO);
+    assert_eq!(Vector2::ZERO.distance(v), 5.0);
+    assert_eq!(v.clamp_length(0.0, 1.0), Vector2::new(0.6, 0.8));
+    assert_eq!(v.clamp_length(10.0, 20.0), Vector2::new(6.0, 8.0));
+}
+
+#[test]
+fn angles_and_rotation() {
+    let x = Vector2::new(1.0, 0.0);
+    let y = Vector2::new(0.0, 1.0);
+
+    assert!((x.angle(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle(x) + FRAC_PI_2).abs() < 1e-6);
+    assert!(x.rotate(FRAC_PI_2).approx_eq(y));
+    assert!(x.rotate(PI).approx_eq(-x));
+
+    let x = Vector3::new(1.0, 0.0, 0.0);
+    let y = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(x.cross(y), Vector3::new(0.0, 0.0, 1.0));
+    assert!((x.angle(y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((y.angle(x) - FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn interpolation() {
+    let a = Vector2::new(0.0, 0.0);
+    let b = Vector2::new(10.0, 0.0);
+
+    assert_eq!(a.lerp(b, 0.25), Vector2::new(2.5, 0.0));
+    assert_eq!(a.move_towards(b, 4.0), Vector2::new(4.0, 0.0));
+    assert_eq!(a.move_towards(b, 20.0), b);
+
+    let v = Vector3::new(-1.0, 5.0, 2.0);
+    assert_eq!(
+        v.clamp(Vector3::ZERO, Vector3::new(3.0, 3.0, 3.0)),
+        Vector3::new(0.0, 3.0, 2.0)
+    );
+    assert_eq!(v.min(Vector3::ONE), Vector3::new(-1.0, 1.0, 1.0));
+    assert_eq!(v.max(Vector3::ONE), Vector3::new(1.0, 5.0, 2.0));
+}
+
+#[test]
+fn reflect_and_refract() {
+    let normal = Vector3::new(0.0, 1.0, 0.0);
+    let incoming = Vector3::new(1.0, -1.0, 0.0);
+
+    assert_eq!(incoming.reflect(normal), Vector3::new(1.0, 1.0, 0.0));
+
+    // The same refractive indices do not bend the ray
+    let ray = incoming.normalize();
+    assert!(ray.refract(normal, 1.0).approx_eq(ray));
+    // Total internal reflection
+    assert_eq!(ray.refract(normal, 1.5), Vector3::ZERO);
+}
+
+#[test]
+fn barycentric() {
+    let a = Vector3::new(0.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 0.0, 0.0);
+    let c = Vector3::new(0.0, 1.0, 0.0);
+
+    assert!(a
+        .barycentric(a, b, c)
+        .approx_eq(Vector3::new(1.0, 0.0, 0.0)));
+    assert!(Vector3::new(0.25, 0.5, 0.0)
+        .barycentric(a, b, c)
+        .approx_eq(Vector3::new(0.25, 0.25, 0.5)));
+}
+
+#[test]
+fn approx_eq() {
+    let v = Vector2::new(1.0, 1000.0);
+    assert!(v.approx_eq(v + Vector2::new(0.0000001, 0.0001)));
+    assert!(!v.approx_eq(v + Vector2::new(0.001, 0.0)));
+}